@@ -0,0 +1,42 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::breadcrumb::{Breadcrumb, BreadcrumbItem, Separator},
+    elements::button::Align,
+    utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let path = vec![
+        ("Bulma", "#"),
+        ("Documentation", "#"),
+        ("Components", "#"),
+        ("Breadcrumb", "#"),
+    ];
+
+    html! {
+        <>
+            <Breadcrumb>
+                { for BreadcrumbItem::from_path(path.clone()) }
+            </Breadcrumb>
+            <Breadcrumb align={Align::Center} separator={Separator::Arrow}>
+                { for BreadcrumbItem::from_path(path.clone()) }
+            </Breadcrumb>
+            <Breadcrumb align={Align::Right} separator={Separator::Bullet}>
+                { for BreadcrumbItem::from_path(path.clone()) }
+            </Breadcrumb>
+            <Breadcrumb size={Size::Small} separator={Separator::Dot}>
+                { for BreadcrumbItem::from_path(path.clone()) }
+            </Breadcrumb>
+            <Breadcrumb size={Size::Large} separator={Separator::Succeeds}>
+                <BreadcrumbItem href="#">{"Bulma"}</BreadcrumbItem>
+                <BreadcrumbItem href="#">{"Documentation"}</BreadcrumbItem>
+                <BreadcrumbItem active=true>{"Breadcrumb"}</BreadcrumbItem>
+            </Breadcrumb>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,50 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::card::{Card, CardContent, CardFooter, CardFooterItem, CardHeader, CardImage},
+    elements::{
+        icon::Icon,
+        image::{Figure, Image, Size},
+    },
+    layout::container::Container,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let expanded = use_state(|| true);
+    let oniconclick = {
+        let expanded = expanded.clone();
+        Callback::from(move |_: MouseEvent| expanded.set(!*expanded))
+    };
+    let icon = html_nested! {
+        <Icon icon={html! {
+            <i class={if *expanded { "fas fa-angle-up" } else { "fas fa-angle-down" }}></i>
+        }} />
+    };
+
+    html! {
+        <Container>
+            <Card>
+                <CardHeader {icon} {oniconclick}>{"Component"}</CardHeader>
+                <CardImage>
+                    <Figure size={Size::Ratio4x3}>
+                        <Image src="https://bulma.io/images/placeholders/1280x960.png" />
+                    </Figure>
+                </CardImage>
+                <CardContent>
+                    if *expanded {
+                        {"Lorem ipsum dolor sit amet, consectetur adipiscing elit."}
+                    }
+                </CardContent>
+                <CardFooter>
+                    <CardFooterItem href="#">{"Save"}</CardFooterItem>
+                    <CardFooterItem href="#">{"Edit"}</CardFooterItem>
+                    <CardFooterItem>{"Read only"}</CardFooterItem>
+                </CardFooter>
+            </Card>
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,52 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::dropdown::{
+        Dropdown, DropdownDivider, DropdownItem, DropdownMenu, DropdownTrigger,
+    },
+    elements::{button::Button, title::Title},
+    layout::container::Container,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let selected = use_state(|| None::<u32>);
+    let onselect = {
+        let selected = selected.clone();
+        Callback::from(move |value: u32| selected.set(Some(value)))
+    };
+    let label = selected
+        .map(|value| format!("Selected {value}"))
+        .unwrap_or("Nothing selected".to_owned());
+
+    html! {
+        <Container>
+            <Title>{label}</Title>
+
+            <Dropdown<u32> {onselect}>
+                <DropdownTrigger>
+                    <Button>{"Pick a number"}</Button>
+                </DropdownTrigger>
+                <DropdownMenu<u32>>
+                    <DropdownItem<u32> value={1} active={*selected == Some(1)}>{"One"}</DropdownItem<u32>>
+                    <DropdownItem<u32> value={2} active={*selected == Some(2)}>{"Two"}</DropdownItem<u32>>
+                    <DropdownDivider />
+                    <DropdownItem<u32> value={3} active={*selected == Some(3)}>{"Three"}</DropdownItem<u32>>
+                </DropdownMenu<u32>>
+            </Dropdown<u32>>
+
+            <Dropdown<&'static str> hoverable=true right=true>
+                <DropdownTrigger>
+                    <Button>{"Hover me"}</Button>
+                </DropdownTrigger>
+                <DropdownMenu<&'static str>>
+                    <DropdownItem<&'static str> value="overview">{"Overview"}</DropdownItem<&'static str>>
+                    <DropdownItem<&'static str> value="settings">{"Settings"}</DropdownItem<&'static str>>
+                </DropdownMenu<&'static str>>
+            </Dropdown<&'static str>>
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,67 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    columns::{Column, Columns},
+    components::menu::{Menu, MenuItem, MenuLabel, MenuList},
+    elements::title::Title,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Page {
+    Dashboard,
+    Customers,
+    TeamSettings,
+    Members,
+    Plugins,
+    AddMember,
+    Payments,
+    Transfers,
+    Balance,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let selected = use_state(|| Page::Dashboard);
+    let onselect = {
+        let selected = selected.clone();
+        Callback::from(move |page: Page| selected.set(page))
+    };
+
+    html! {
+        <Columns>
+            <Column>
+                <Menu<Page> selected={*selected} {onselect}>
+                    <MenuLabel>{"General"}</MenuLabel>
+                    <MenuList<Page>>
+                        <MenuItem<Page> value={Page::Dashboard} label="Dashboard" />
+                        <MenuItem<Page> value={Page::Customers} label="Customers" />
+                    </MenuList<Page>>
+
+                    <MenuLabel>{"Administration"}</MenuLabel>
+                    <MenuList<Page>>
+                        <MenuItem<Page> value={Page::TeamSettings} label="Manage Your Team" collapsible=true>
+                            <MenuList<Page>>
+                                <MenuItem<Page> value={Page::Members} label="Members" />
+                                <MenuItem<Page> value={Page::Plugins} label="Plugins" />
+                                <MenuItem<Page> value={Page::AddMember} label="Add a member" />
+                            </MenuList<Page>>
+                        </MenuItem<Page>>
+                    </MenuList<Page>>
+
+                    <MenuLabel>{"Transactions"}</MenuLabel>
+                    <MenuList<Page>>
+                        <MenuItem<Page> value={Page::Payments} label="Payments" />
+                        <MenuItem<Page> value={Page::Transfers} label="Transfers" />
+                        <MenuItem<Page> value={Page::Balance} label="Balance" />
+                    </MenuList<Page>>
+                </Menu<Page>>
+            </Column>
+            <Column>
+                <Title>{format!("{:?}", *selected)}</Title>
+            </Column>
+        </Columns>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,62 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::message::{Message, MessageBody, MessageHeader},
+    elements::{button::Button, notification::Notification},
+    helpers::color::Color,
+    layout::container::Container,
+    utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let hidden = use_state(|| false);
+    let onhide = {
+        let hidden = hidden.clone();
+        Callback::from(move |_| hidden.set(true))
+    };
+    let onshow = {
+        let hidden = hidden.clone();
+        Callback::from(move |_| hidden.set(false))
+    };
+    let removed = use_state(|| false);
+    let onremove = {
+        let removed = removed.clone();
+        Callback::from(move |_| removed.set(true))
+    };
+    let notification = use_state(|| true);
+    let ondismiss = {
+        let notification = notification.clone();
+        Callback::from(move |_| notification.set(false))
+    };
+
+    html! {
+        <Container>
+            <Message color={Color::Info} hidden={*hidden} ondismiss={onhide}>
+                <MessageHeader>{"Hidden when dismissed"}</MessageHeader>
+                <MessageBody>{"This message is kept in the page, but hidden."}</MessageBody>
+            </Message>
+            <Button onclick={onshow}>{"Show the message again"}</Button>
+
+            if !*removed {
+                <Message color={Color::Danger} size={Size::Small} ondismiss={onremove}>
+                    <MessageHeader>{"Removed when dismissed"}</MessageHeader>
+                    <MessageBody>{"This message is removed from the page."}</MessageBody>
+                </Message>
+            }
+
+            <Message>
+                <MessageBody>{"A message without a header."}</MessageBody>
+            </Message>
+
+            if *notification {
+                <Notification color={Color::Primary} {ondismiss}>
+                    {"Notifications can be dismissed as well."}
+                </Notification>
+            }
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,67 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::modal::{
+        Modal, ModalCard, ModalCardBody, ModalCardFoot, ModalCardHead, ModalContent,
+    },
+    elements::{
+        button::{Button, Buttons},
+        r#box::Box,
+        title::Title,
+    },
+    helpers::color::Color,
+    layout::container::Container,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let content_open = use_state(|| false);
+    let card_open = use_state(|| false);
+
+    let open_content = {
+        let content_open = content_open.clone();
+        Callback::from(move |_| content_open.set(true))
+    };
+    let close_content = {
+        let content_open = content_open.clone();
+        Callback::from(move |_| content_open.set(false))
+    };
+    let open_card = {
+        let card_open = card_open.clone();
+        Callback::from(move |_| card_open.set(true))
+    };
+    let close_card = {
+        let card_open = card_open.clone();
+        Callback::from(move |_| card_open.set(false))
+    };
+
+    html! {
+        <Container>
+            <Title>{"Modals"}</Title>
+            <Buttons>
+                <Button onclick={open_content}>{"Open modal"}</Button>
+                <Button color={Color::Primary} onclick={open_card}>{"Open modal card"}</Button>
+            </Buttons>
+
+            <Modal open={*content_open} onclose={close_content}>
+                <ModalContent>
+                    <Box>{"Close me by clicking the background, the close button or pressing Escape."}</Box>
+                </ModalContent>
+            </Modal>
+
+            <Modal open={*card_open} onclose={close_card.clone()} close_on_background=false>
+                <ModalCard>
+                    <ModalCardHead>{"Modal title"}</ModalCardHead>
+                    <ModalCardBody>{"This modal does not close when its background is clicked."}</ModalCardBody>
+                    <ModalCardFoot>
+                        <Button color={Color::Success} onclick={close_card.reform(|_| ())}>{"Save changes"}</Button>
+                        <Button onclick={close_card.reform(|_| ())}>{"Cancel"}</Button>
+                    </ModalCardFoot>
+                </ModalCard>
+            </Modal>
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
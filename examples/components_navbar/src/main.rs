@@ -0,0 +1,77 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::navbar::{
+        Navbar, NavbarBrand, NavbarDivider, NavbarDropdown, NavbarEnd, NavbarItem, NavbarMenu,
+        NavbarStart,
+    },
+    elements::{
+        button::{Button, Buttons},
+        title::Title,
+    },
+    helpers::color::Color,
+    layout::container::Container,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    html! {
+        <>
+        <Navbar shadow=true>
+            <NavbarBrand>
+                <NavbarItem href="https://bulma.io">
+                    <strong>{"Yew and Bulma"}</strong>
+                </NavbarItem>
+            </NavbarBrand>
+
+            <NavbarMenu>
+                <NavbarStart>
+                    <NavbarItem active=true>{"Home"}</NavbarItem>
+                    <NavbarItem>{"Documentation"}</NavbarItem>
+
+                    <NavbarDropdown hoverable=true label={html! {"More"}}>
+                        <NavbarItem>{"About"}</NavbarItem>
+                        <NavbarItem>{"Jobs"}</NavbarItem>
+                        <NavbarItem>{"Contact"}</NavbarItem>
+                        <NavbarDivider />
+                        <NavbarItem>{"Report an issue"}</NavbarItem>
+                    </NavbarDropdown>
+                </NavbarStart>
+
+                <NavbarEnd>
+                    <NavbarItem>
+                        <Buttons>
+                            <Button color={Color::Primary}>
+                                <strong>{"Sign up"}</strong>
+                            </Button>
+                            <Button light=true>{"Log in"}</Button>
+                        </Buttons>
+                    </NavbarItem>
+                </NavbarEnd>
+            </NavbarMenu>
+        </Navbar>
+
+        <Container>
+            <Title>{"Colored and transparent navbar"}</Title>
+        </Container>
+
+        <Navbar color={Color::Info} transparent=true>
+            <NavbarBrand>
+                <NavbarItem>{"Brand"}</NavbarItem>
+            </NavbarBrand>
+
+            <NavbarMenu>
+                <NavbarEnd>
+                    <NavbarDropdown right=true boxed=true label={html! {"Account"}}>
+                        <NavbarItem>{"Settings"}</NavbarItem>
+                        <NavbarItem>{"Log out"}</NavbarItem>
+                    </NavbarDropdown>
+                </NavbarEnd>
+            </NavbarMenu>
+        </Navbar>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,44 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::pagination::Pagination,
+    elements::{button::Align, title::Title},
+    layout::container::Container,
+    utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let current = use_state(|| 46);
+    let onpagechange = {
+        let current = current.clone();
+        Callback::from(move |page: usize| current.set(page))
+    };
+
+    html! {
+        <Container>
+            <Title>{format!("Page {}", *current)}</Title>
+
+            <Pagination current={*current} total_pages={86} onpagechange={onpagechange.clone()} />
+
+            <Pagination
+                current={*current}
+                total_pages={86}
+                onpagechange={onpagechange.clone()}
+                rounded=true
+                align={Align::Center} />
+
+            <Pagination
+                current={*current}
+                total_pages={86}
+                {onpagechange}
+                siblings={2}
+                boundaries={2}
+                size={Size::Small}
+                align={Align::Right} />
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
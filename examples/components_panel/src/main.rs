@@ -0,0 +1,66 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::panel::{
+        Panel, PanelBlock, PanelHeading, PanelIcon, PanelSearch, PanelTab, PanelTabs,
+    },
+    helpers::color::Color,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Filter {
+    All,
+    Public,
+    Private,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let selected = use_state(|| Filter::All);
+    let onchange = {
+        let selected = selected.clone();
+        Callback::from(move |filter: Filter| selected.set(filter))
+    };
+    let remember = use_state(|| false);
+    let oncheck = {
+        let remember = remember.clone();
+        Callback::from(move |checked: bool| remember.set(checked))
+    };
+    let filter = Callback::from(|(query, text): (String, AttrValue)| {
+        text.to_lowercase().contains(&query.to_lowercase())
+    });
+
+    html! {
+        <Panel color={Color::Primary} {filter}>
+            <PanelHeading>{"Repositories"}</PanelHeading>
+            <PanelSearch />
+            <PanelTabs<Filter> selected={*selected} {onchange}>
+                <PanelTab<Filter> value={Filter::All} label="All" />
+                <PanelTab<Filter> value={Filter::Public} label="Public" />
+                <PanelTab<Filter> value={Filter::Private} label="Private" />
+            </PanelTabs<Filter>>
+            <PanelBlock text="bulma" active=true href="#">
+                <PanelIcon><i class="fas fa-book" aria-hidden="true"></i></PanelIcon>
+                {"bulma"}
+            </PanelBlock>
+            <PanelBlock text="marksheet" href="#">
+                <PanelIcon><i class="fas fa-book" aria-hidden="true"></i></PanelIcon>
+                {"marksheet"}
+            </PanelBlock>
+            <PanelBlock text="minireset.css" href="#">
+                <PanelIcon><i class="fas fa-book" aria-hidden="true"></i></PanelIcon>
+                {"minireset.css"}
+            </PanelBlock>
+            <PanelBlock text="jgthms.github.io" href="#">
+                <PanelIcon><i class="fas fa-code-branch" aria-hidden="true"></i></PanelIcon>
+                {"jgthms.github.io"}
+            </PanelBlock>
+            <PanelBlock checkbox=true checked={*remember} {oncheck}>
+                {"remember me"}
+            </PanelBlock>
+        </Panel>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
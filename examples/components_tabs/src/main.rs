@@ -0,0 +1,60 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    components::tabs::{Style, Tab, TabPanel, Tabs},
+    elements::{button::Align, icon::Icon, title::Title},
+    layout::container::Container,
+    utils::size::Size,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Settings {
+    Profile,
+    Account,
+    Notifications,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let selected = use_state(|| Settings::Profile);
+    let onchange = {
+        let selected = selected.clone();
+        Callback::from(move |value: Settings| selected.set(value))
+    };
+    let profile_icon = html_nested! {
+        <Icon size={Size::Small} icon={html! { <i class="fas fa-user"></i> }} />
+    };
+
+    html! {
+        <Container>
+            <Title>{"Settings"}</Title>
+
+            <Tabs<Settings> selected={*selected} {onchange} style={Style::Boxed}>
+                <Tab<Settings> value={Settings::Profile} label="Profile" icon={profile_icon} />
+                <Tab<Settings> value={Settings::Account} label="Account" />
+                <Tab<Settings> value={Settings::Notifications} label="Notifications" />
+
+                <TabPanel<Settings> value={Settings::Profile}>{"Edit your profile."}</TabPanel<Settings>>
+                <TabPanel<Settings> value={Settings::Account}>{"Manage your account."}</TabPanel<Settings>>
+                <TabPanel<Settings> value={Settings::Notifications}>
+                    {"Choose which notifications you receive."}
+                </TabPanel<Settings>>
+            </Tabs<Settings>>
+
+            <Tabs<Settings> selected={*selected} style={Style::ToggleRounded} align={Align::Center} size={Size::Small}>
+                <Tab<Settings> value={Settings::Profile} label="Profile" />
+                <Tab<Settings> value={Settings::Account} label="Account" />
+                <Tab<Settings> value={Settings::Notifications} label="Notifications" />
+            </Tabs<Settings>>
+
+            <Tabs<Settings> selected={*selected} full_width=true>
+                <Tab<Settings> value={Settings::Profile} label="Profile" />
+                <Tab<Settings> value={Settings::Account} label="Account" />
+                <Tab<Settings> value={Settings::Notifications} label="Notifications" />
+            </Tabs<Settings>>
+        </Container>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
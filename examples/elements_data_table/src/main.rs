@@ -0,0 +1,132 @@
+use std::{collections::HashSet, rc::Rc};
+
+use yew::prelude::*;
+use yew_and_bulma::elements::{
+    button::Buttons,
+    data_table::{Column, DataTable, Direction, KeyExtractor, RowKey, SelectionMode},
+    table_export::{ExportAction, ExportFormat, TableExportButton},
+    tag::Tag,
+};
+
+#[derive(PartialEq)]
+struct Team {
+    name: &'static str,
+    played: u32,
+    won: u32,
+    drawn: u32,
+    lost: u32,
+}
+
+impl Team {
+    fn points(&self) -> u32 {
+        self.won * 3 + self.drawn
+    }
+}
+
+fn total(teams: &[&Team], value: fn(&Team) -> u32) -> Html {
+    html! { <strong>{ teams.iter().copied().map(value).sum::<u32>() }</strong> }
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let rows = Rc::new(vec![
+        Team {
+            name: "Leicester City",
+            played: 38,
+            won: 23,
+            drawn: 12,
+            lost: 3,
+        },
+        Team {
+            name: "Arsenal",
+            played: 38,
+            won: 20,
+            drawn: 11,
+            lost: 7,
+        },
+        Team {
+            name: "Tottenham Hotspur",
+            played: 38,
+            won: 19,
+            drawn: 13,
+            lost: 6,
+        },
+        Team {
+            name: "Manchester City",
+            played: 38,
+            won: 19,
+            drawn: 9,
+            lost: 10,
+        },
+    ]);
+    let columns = vec![
+        Column::new("Team", |team: &Team| html! { team.name })
+            .with_sort_key(|team: &Team| team.name)
+            .with_text(|team: &Team| team.name.to_owned())
+            .with_footer(|teams: &[&Team]| html! { format!("{} teams", teams.len()) }),
+        Column::new("Played", |team: &Team| html! { team.played })
+            .with_text(|team: &Team| team.played.to_string()),
+        Column::new("Won", |team: &Team| html! { team.won })
+            .with_sort_key(|team: &Team| team.won)
+            .with_text(|team: &Team| team.won.to_string())
+            .with_footer(|teams: &[&Team]| total(teams, |team| team.won)),
+        Column::new("Drawn", |team: &Team| html! { team.drawn })
+            .with_sort_key(|team: &Team| team.drawn)
+            .with_text(|team: &Team| team.drawn.to_string())
+            .with_footer(|teams: &[&Team]| total(teams, |team| team.drawn)),
+        Column::new("Lost", |team: &Team| html! { team.lost })
+            .with_sort_key(|team: &Team| team.lost)
+            .with_text(|team: &Team| team.lost.to_string())
+            .with_footer(|teams: &[&Team]| total(teams, |team| team.lost)),
+        Column::new(
+            "Points",
+            |team: &Team| html! { <Tag>{ team.points() }</Tag> },
+        )
+        .with_sort_key(Team::points)
+        .with_text(|team: &Team| team.points().to_string()),
+    ];
+    let sort = use_state(|| vec![(AttrValue::from("Points"), Direction::Descending)]);
+    let onsortchange = {
+        let sort = sort.clone();
+        Callback::from(move |value| sort.set(value))
+    };
+    let selected = use_state(HashSet::<RowKey>::new);
+    let onselectionchange = {
+        let selected = selected.clone();
+        Callback::from(move |value| selected.set(value))
+    };
+    let row_key = KeyExtractor::new(|team: &Team| AttrValue::from(team.name));
+    let sort_icon = Callback::from(|direction: Option<Direction>| {
+        let name = match direction {
+            Some(Direction::Ascending) => "arrow_upward",
+            Some(Direction::Descending) => "arrow_downward",
+            None => "swap_vert",
+        };
+
+        html! { <span class="material-symbols-outlined">{ name }</span> }
+    });
+
+    html! {
+        <>
+            <p class="mb-4">
+                { "Click a header to sort by it, shift-click to sort by several columns. " }
+                { "Shift-click a checkbox to select a range of teams." }
+            </p>
+            <Buttons>
+                <TableExportButton<Team> rows={rows.clone()} columns={columns.clone()}
+                    sort={(*sort).clone()} file_name="premier-league" bom=true />
+                <TableExportButton<Team> rows={rows.clone()} columns={columns.clone()}
+                    sort={(*sort).clone()} format={ExportFormat::Tsv}
+                    action={ExportAction::Clipboard} />
+            </Buttons>
+            <DataTable<Team> {rows} {columns} sort={(*sort).clone()} {onsortchange} {sort_icon}
+                {row_key} selection={SelectionMode::Multiple} {onselectionchange}
+                striped=true hoverable=true full_width=true scrollable=true />
+            <p>{ format!("{} teams selected", selected.len()) }</p>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,82 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_and_bulma::elements::{
+    data_table::{Column, PaginatedTable},
+    table_filter::{Filter, FilterValue, Filters},
+};
+
+#[derive(PartialEq)]
+struct Employee {
+    name: String,
+    department: &'static str,
+    salary: u32,
+    hired: String,
+}
+
+const DEPARTMENTS: [&str; 4] = ["Engineering", "Marketing", "Sales", "Support"];
+
+fn employees() -> Vec<Employee> {
+    (1..=120)
+        .map(|id| Employee {
+            name: format!("Employee {id}"),
+            department: DEPARTMENTS[id % DEPARTMENTS.len()],
+            salary: 30_000 + (id as u32 * 7_919) % 70_000,
+            hired: format!(
+                "20{:02}-{:02}-{:02}",
+                10 + id % 14,
+                1 + id % 12,
+                1 + id % 28
+            ),
+        })
+        .collect()
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let rows = use_memo(|_| employees(), ());
+    let columns = vec![
+        Column::new("Name", |employee: &Employee| html! { &employee.name })
+            .with_text(|employee: &Employee| employee.name.clone())
+            .with_filter(Filter::text(|employee: &Employee| employee.name.clone())),
+        Column::new(
+            "Department",
+            |employee: &Employee| html! { employee.department },
+        )
+        .with_text(|employee: &Employee| employee.department.to_owned())
+        .with_sort_key(|employee: &Employee| employee.department)
+        .with_filter(Filter::options(DEPARTMENTS, |employee: &Employee| {
+            employee.department
+        })),
+        Column::new("Salary", |employee: &Employee| html! { employee.salary })
+            .with_sort_key(|employee: &Employee| employee.salary)
+            .with_filter(Filter::range(|employee: &Employee| {
+                f64::from(employee.salary)
+            })),
+        Column::new("Hired", |employee: &Employee| html! { &employee.hired })
+            .with_sort_key(|employee: &Employee| employee.hired.clone())
+            .with_filter(Filter::date_range(|employee: &Employee| {
+                employee.hired.clone()
+            })),
+    ];
+    let filters = use_state(|| {
+        Filters::default().with_filter("Salary", FilterValue::Range(Some(50_000.0), None))
+    });
+    let onfilterchange = {
+        let filters = filters.clone();
+        Callback::from(move |value| filters.set(value))
+    };
+
+    html! {
+        <>
+            <PaginatedTable<Employee> rows={Rc::clone(&rows)} {columns}
+                filters={(*filters).clone()} {onfilterchange} searchable=true
+                striped=true full_width=true scrollable=true />
+            <p>{ format!("{} filtered columns", filters.columns().count()) }</p>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
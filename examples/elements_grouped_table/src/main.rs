@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_and_bulma::elements::{
+    data_table::{Column, DataTable, KeyExtractor, RowDetail},
+    table::{Table, TableData, TableGroup, TableHeader, TableRow},
+};
+
+#[derive(PartialEq)]
+struct Employee {
+    name: String,
+    department: &'static str,
+    salary: u32,
+}
+
+const DEPARTMENTS: [&str; 3] = ["Engineering", "Marketing", "Sales"];
+
+fn employees() -> Vec<Employee> {
+    (1..=20)
+        .map(|id| Employee {
+            name: format!("Employee {id}"),
+            department: DEPARTMENTS[id % DEPARTMENTS.len()],
+            salary: 30_000 + (id as u32 * 7_919) % 70_000,
+        })
+        .collect()
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let rows = use_memo(|_| employees(), ());
+    let columns = vec![
+        Column::new("Name", |employee: &Employee| html! { &employee.name }),
+        Column::new("Salary", |employee: &Employee| html! { employee.salary })
+            .with_sort_key(|employee: &Employee| employee.salary),
+    ];
+    let group_by = KeyExtractor::new(|employee: &Employee| AttrValue::from(employee.department));
+    let detail = RowDetail::new(|employee: &Employee| {
+        html! {
+            { format!("{} works in {} and earns {} a year.", employee.name, employee.department, employee.salary) }
+        }
+    });
+    let expanded = use_state(|| false);
+    let onexpandchange = {
+        let expanded = expanded.clone();
+        Callback::from(move |value| expanded.set(value))
+    };
+
+    html! {
+        <>
+            <DataTable<Employee> rows={Rc::clone(&rows)} {columns} {group_by} {detail}
+                hoverable=true full_width=true />
+
+            <Table full_width=true>
+                <TableHeader>{ "Name" }</TableHeader>
+                <TableHeader>{ "Role" }</TableHeader>
+
+                <TableGroup label="Administrators" expanded={*expanded} {onexpandchange}>
+                    <TableRow detail={Callback::from(|_| html! { "Ann has been an admin since 2019." })}>
+                        <TableData>{ "Ann" }</TableData>
+                        <TableData>{ "Admin" }</TableData>
+                    </TableRow>
+                </TableGroup>
+                <TableGroup label="Editors">
+                    <TableRow>
+                        <TableData>{ "Bob" }</TableData>
+                        <TableData>{ "Editor" }</TableData>
+                    </TableRow>
+                    <TableRow>
+                        <TableData>{ "Cleo" }</TableData>
+                        <TableData>{ "Editor" }</TableData>
+                    </TableRow>
+                </TableGroup>
+            </Table>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+
+#[derive(PartialEq)]
+struct Country {
+    rank: usize,
+    name: String,
+}
+
+fn columns() -> Vec<Column<Country>> {
+    vec![
+        Column::new("Rank", |country: &Country| html! { country.rank })
+            .with_sort_key(|country: &Country| country.rank),
+        Column::new("Name", |country: &Country| html! { &country.name })
+            .with_sort_key(|country: &Country| country.name.clone()),
+    ]
+}
+
+fn server_columns() -> Vec<Column<Country>> {
+    vec![
+        Column::new("Rank", |country: &Country| html! { country.rank }),
+        Column::new("Name", |country: &Country| html! { &country.name }),
+    ]
+}
+
+fn country(rank: usize) -> Country {
+    Country {
+        rank,
+        name: format!("Country {rank}"),
+    }
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let rows = Rc::new((1..=195).map(country).collect::<Vec<_>>());
+
+    let page = use_state(|| 1);
+    let page_size = 10;
+    let server_rows = Rc::new(
+        ((*page - 1) * page_size + 1..=(*page * page_size).min(1000))
+            .map(country)
+            .collect::<Vec<_>>(),
+    );
+    let onpagechange = {
+        let page = page.clone();
+        Callback::from(move |value| page.set(value))
+    };
+
+    html! {
+        <>
+            <h2 class="title is-4">{ "Paged by the table" }</h2>
+            <PaginatedTable<Country> {rows} columns={columns()} page_sizes={vec![5, 10, 25]}
+                striped=true full_width=true />
+            <h2 class="title is-4">{ "Paged by the server" }</h2>
+            <PaginatedTable<Country> rows={server_rows} columns={server_columns()} page={*page}
+                {page_size} page_sizes={Vec::new()} total_rows={1000} {onpagechange}
+                striped=true full_width=true />
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_and_bulma::elements::{
+    data_table::{Column, DataTable},
+    table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll},
+};
+
+#[derive(PartialEq)]
+struct Reading {
+    id: u32,
+    value: f64,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let rows = Rc::new(
+        (1..=50_000)
+            .map(|id| Reading {
+                id,
+                value: f64::from(id).sqrt(),
+            })
+            .collect::<Vec<_>>(),
+    );
+    let columns = vec![
+        Column::new("Reading", |reading: &Reading| html! { reading.id })
+            .with_sort_key(|reading: &Reading| reading.id),
+        Column::new(
+            "Value",
+            |reading: &Reading| html! { format!("{:.3}", reading.value) },
+        ),
+    ];
+
+    html! {
+        <>
+            <h2 class="title is-4">{ "50 000 rows with a fixed height" }</h2>
+            <DataTable<Reading> {rows} {columns} scrollable=true striped=true full_width=true
+                virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0)).with_height(320.0)} />
+            <h2 class="title is-4">{ "10 000 measured rows" }</h2>
+            <Table scrollable=true full_width=true
+                virtual_scroll={VirtualScroll::new(RowHeight::Measured(40.0)).with_overscan(10)}>
+                <TableHeader>{ "Line" }</TableHeader>
+                <TableHeader>{ "Text" }</TableHeader>
+                { for (1..=10_000).map(|line| html_nested! {
+                    <TableRow>
+                        <TableData>{ line }</TableData>
+                        <TableData>{ ("Lorem ipsum dolor sit amet. ").repeat(line % 7 + 1) }</TableData>
+                    </TableRow>
+                }) }
+            </Table>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,87 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    form::{Checkbox, Control, Field, Help, Label, RadioGroup, Textarea},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Contact {
+    Email,
+    Phone,
+    Mail,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let message = use_state(String::new);
+    let oninput = {
+        let message = message.clone();
+        Callback::from(move |value: String| message.set(value))
+    };
+
+    let agreed = use_state(|| false);
+    let onagree = {
+        let agreed = agreed.clone();
+        Callback::from(move |value: bool| agreed.set(value))
+    };
+
+    let contact = use_state(|| Contact::Email);
+    let oncontact = {
+        let contact = contact.clone();
+        Callback::from(move |value: Contact| contact.set(value))
+    };
+    let contacts = vec![
+        (Contact::Email, AttrValue::from("Email")),
+        (Contact::Phone, AttrValue::from("Phone")),
+        (Contact::Mail, AttrValue::from("Mail")),
+    ];
+
+    html! {
+        <>
+            <Field>
+                <Label>{"Message"}</Label>
+                <Control>
+                    <Textarea rows={4} placeholder="Your message"
+                        value={(*message).clone()} {oninput} />
+                </Control>
+                <Help>{format!("{} characters", message.chars().count())}</Help>
+            </Field>
+
+            <Field>
+                <Label>{"Fixed size textarea"}</Label>
+                <Control>
+                    <Textarea fixed_size=true color={Color::Info} size={Size::Small} />
+                </Control>
+            </Field>
+
+            <Field>
+                <Label>{"Preferred contact"}</Label>
+                <RadioGroup<Contact> name="contact" options={contacts}
+                    value={*contact} onchange={oncontact} />
+                <Help>{format!("Selected: {:?}", *contact)}</Help>
+            </Field>
+
+            <Field>
+                <Control>
+                    <Checkbox checked={*agreed} onchange={onagree}>
+                        {" I agree to the terms and conditions"}
+                    </Checkbox>
+                </Control>
+                <Help color={if *agreed { Color::Success } else { Color::Danger }}>
+                    { if *agreed { "Thank you!" } else { "You need to agree first" } }
+                </Help>
+            </Field>
+
+            <Field>
+                <Control>
+                    <Checkbox disabled=true>{" Save my preferences"}</Checkbox>
+                </Control>
+            </Field>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,99 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    form::{BulmaForm, Help},
+    helpers::color::Color,
+    layout::{container::Container, section::Section},
+};
+
+#[derive(Clone, PartialEq)]
+enum Role {
+    Viewer,
+    Editor,
+    Admin,
+}
+
+// Deriving it requires `#[default]`, which is not available in Rust 1.60.
+#[allow(clippy::derivable_impls)]
+impl Default for Role {
+    fn default() -> Self {
+        Role::Viewer
+    }
+}
+
+impl Role {
+    fn options() -> Vec<(Role, AttrValue)> {
+        vec![
+            (Role::Viewer, AttrValue::from("Viewer")),
+            (Role::Editor, AttrValue::from("Editor")),
+            (Role::Admin, AttrValue::from("Admin")),
+        ]
+    }
+}
+
+fn valid_email(email: &str) -> Result<(), String> {
+    if email.contains('@') {
+        Ok(())
+    } else {
+        Err("This email is invalid".to_owned())
+    }
+}
+
+fn adult(age: &u32) -> Result<(), String> {
+    if *age < 18 {
+        Err("Users need to be at least 18 years old".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default, PartialEq, BulmaForm)]
+#[bulma_form(submit = "Save user")]
+struct User {
+    #[bulma_form(skip)]
+    id: u64,
+    #[bulma_form(required, placeholder = "Jane Doe")]
+    name: String,
+    #[bulma_form(
+        input_type = "Email",
+        validate = "valid_email",
+        help = "Used to log in"
+    )]
+    email: String,
+    #[bulma_form(validate = "adult")]
+    age: u32,
+    #[bulma_form(options = "Role::options")]
+    role: Role,
+    #[bulma_form(label = "Active account")]
+    active: bool,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let saved = use_state(|| None::<User>);
+    let onsubmit = {
+        let saved = saved.clone();
+        Callback::from(move |user: User| saved.set(Some(user)))
+    };
+    let initial = User {
+        id: 1,
+        active: true,
+        ..User::default()
+    };
+
+    html! {
+        <Section>
+            <Container>
+                <UserForm {initial} {onsubmit} />
+                if let Some(user) = &*saved {
+                    <Help color={Color::Success}>
+                        {format!("Saved user #{} named {} ({})", user.id, user.name, user.email)}
+                    </Help>
+                }
+            </Container>
+        </Section>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,32 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    elements::button::Align, form::file::FileInput, helpers::color::Color, utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let total_size = use_state(|| 0.0);
+    let onfiles = {
+        let total_size = total_size.clone();
+        Callback::from(move |files: Vec<web_sys::File>| {
+            total_size.set(files.iter().map(|file| file.size()).sum());
+        })
+    };
+    let icon = html! { <i class="fas fa-upload"></i> };
+
+    html! {
+        <>
+            <FileInput has_name=true multiple=true icon={icon.clone()} {onfiles} />
+            <p>{format!("Total size: {} bytes", *total_size)}</p>
+
+            <FileInput color={Color::Primary} has_name=true fullwidth=true icon={icon.clone()} />
+            <FileInput color={Color::Info} boxed=true align={Align::Center}
+                label="Drop a file here" icon={icon.clone()} />
+            <FileInput size={Size::Large} align={Align::Right} has_name=true icon={icon} />
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,85 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    elements::{
+        button::{Align, Button},
+        icon::Icon,
+    },
+    form::{Control, Field, FieldBody, FieldLabel, Help, Label},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    html! {
+        <>
+            <Field>
+                <Label html_for="name">{"Name"}</Label>
+                <Control>
+                    <input id="name" class="input" type="text" placeholder="Text input" />
+                </Control>
+            </Field>
+
+            <Field>
+                <Label html_for="username">{"Username"}</Label>
+                <Control
+                    icon_left={html_nested! {
+                        <Icon size={Size::Small} icon={html! { <i class="fas fa-user"></i> }} />
+                    }}
+                    icon_right={html_nested! {
+                        <Icon size={Size::Small} icon={html! { <i class="fas fa-check"></i> }} />
+                    }}>
+                    <input id="username" class="input is-success" type="text" value="bulma" />
+                </Control>
+                <Help color={Color::Success}>{"This username is available"}</Help>
+            </Field>
+
+            <Field>
+                <Label>{"Search"}</Label>
+                <Control loading=true>
+                    <input class="input" type="text" placeholder="Loading input" />
+                </Control>
+            </Field>
+
+            <Field addons=true>
+                <Control expanded=true>
+                    <input class="input" type="text" placeholder="Find a repository" />
+                </Control>
+                <Control>
+                    <Button color={Color::Info}>{"Search"}</Button>
+                </Control>
+            </Field>
+
+            <Field grouped=true align={Align::Center} multiline=true>
+                <Control>
+                    <Button color={Color::Link}>{"Submit"}</Button>
+                </Control>
+                <Control>
+                    <Button>{"Cancel"}</Button>
+                </Control>
+            </Field>
+
+            <Field horizontal=true>
+                <FieldLabel size={Size::Normal}>
+                    <Label>{"From"}</Label>
+                </FieldLabel>
+                <FieldBody>
+                    <Field>
+                        <Control expanded=true>
+                            <input class="input" type="text" placeholder="Name" />
+                        </Control>
+                    </Field>
+                    <Field>
+                        <Control expanded=true>
+                            <input class="input" type="email" placeholder="Email" />
+                        </Control>
+                    </Field>
+                </FieldBody>
+            </Field>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
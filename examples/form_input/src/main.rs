@@ -0,0 +1,75 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    form::{
+        input::{Input, InputType},
+        Control, Field, Help, Label,
+    },
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+#[function_component(App)]
+fn app() -> Html {
+    let email = use_state(String::new);
+    let oninput = {
+        let email = email.clone();
+        Callback::from(move |value: String| email.set(value))
+    };
+    let color = if email.is_empty() {
+        None
+    } else if email.contains('@') {
+        Some(Color::Success)
+    } else {
+        Some(Color::Danger)
+    };
+
+    html! {
+        <>
+            <Field>
+                <Label>{"Email"}</Label>
+                <Control>
+                    <Input input_type={InputType::Email} placeholder="Email input"
+                        {color} value={(*email).clone()} {oninput} />
+                </Control>
+                <Help>{format!("You typed: {}", *email)}</Help>
+            </Field>
+
+            <Field>
+                <Label>{"Password"}</Label>
+                <Control>
+                    <Input input_type={InputType::Password} rounded=true />
+                </Control>
+            </Field>
+
+            <Field>
+                <Label>{"Sizes"}</Label>
+                <Control>
+                    <Input size={Size::Small} placeholder="Small input" />
+                </Control>
+                <Control>
+                    <Input size={Size::Large} placeholder="Large input" />
+                </Control>
+            </Field>
+
+            <Field>
+                <Label>{"States"}</Label>
+                <Control>
+                    <Input value="Read-only input" readonly=true />
+                </Control>
+                <Control>
+                    <Input value="Static input" is_static=true />
+                </Control>
+                <Control>
+                    <Input placeholder="Disabled input" disabled=true />
+                </Control>
+                <Control>
+                    <Input placeholder="Loading input" loading=true />
+                </Control>
+            </Field>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,84 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    form::{select::Select, Control, Field, Help, Label},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Plan {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Topic {
+    Releases,
+    Security,
+    Newsletter,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let plans = vec![
+        (Plan::Free, AttrValue::from("Free")),
+        (Plan::Pro, AttrValue::from("Pro")),
+        (Plan::Enterprise, AttrValue::from("Enterprise")),
+    ];
+    let plan = use_state(|| None);
+    let onchange = {
+        let plan = plan.clone();
+        Callback::from(move |selected: Plan| plan.set(Some(selected)))
+    };
+
+    let topics = vec![
+        (Topic::Releases, AttrValue::from("Releases")),
+        (Topic::Security, AttrValue::from("Security advisories")),
+        (Topic::Newsletter, AttrValue::from("Newsletter")),
+    ];
+    let selected_topics = use_state(Vec::new);
+    let onchangemultiple = {
+        let selected_topics = selected_topics.clone();
+        Callback::from(move |selected: Vec<Topic>| selected_topics.set(selected))
+    };
+
+    html! {
+        <>
+            <Field>
+                <Label>{"Plan"}</Label>
+                <Control>
+                    <Select<Plan> options={plans.clone()} value={*plan} {onchange}
+                        placeholder="Pick a plan" color={Color::Primary} />
+                </Control>
+                <Help>{format!("Selected plan: {:?}", *plan)}</Help>
+            </Field>
+
+            <Field>
+                <Label>{"Topics"}</Label>
+                <Control>
+                    <Select<Topic> options={topics} multiple=true
+                        values={(*selected_topics).clone()} {onchangemultiple} />
+                </Control>
+                <Help>{format!("Selected topics: {:?}", *selected_topics)}</Help>
+            </Field>
+
+            <Field>
+                <Label>{"Styles"}</Label>
+                <Control>
+                    <Select<Plan> options={plans.clone()} rounded=true size={Size::Small} />
+                </Control>
+                <Control>
+                    <Select<Plan> options={plans.clone()} loading=true />
+                </Control>
+                <Control>
+                    <Select<Plan> options={plans} fullwidth=true disabled=true />
+                </Control>
+            </Field>
+        </>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,125 @@
+use yew::prelude::*;
+use yew_and_bulma::{
+    elements::button::{Button, Buttons},
+    form::{
+        input::{Input, InputType},
+        state::{use_form, FormState},
+        Checkbox, Control, Field, Help, Label,
+    },
+    helpers::color::Color,
+};
+
+#[derive(Clone, Default, PartialEq)]
+struct SignUp {
+    email: String,
+    password: String,
+    confirmation: String,
+    terms: bool,
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let submitted = use_state(|| None::<String>);
+    let form = use_form(|| {
+        FormState::new(SignUp::default())
+            .with_validator("email", |values| {
+                if values.email.contains('@') {
+                    Ok(())
+                } else {
+                    Err("This email is invalid".to_owned())
+                }
+            })
+            .with_validator("password", |values| {
+                if values.password.len() < 8 {
+                    Err("The password needs at least 8 characters".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .with_validator("confirmation", |values| {
+                if values.confirmation != values.password {
+                    Err("The passwords do not match".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .with_validator("terms", |values| {
+                if values.terms {
+                    Ok(())
+                } else {
+                    Err("You need to agree to the terms".to_owned())
+                }
+            })
+    });
+    let onsubmit = {
+        let submitted = submitted.clone();
+        form.onsubmit(Callback::from(move |values: SignUp| {
+            submitted.set(Some(values.email))
+        }))
+    };
+    let onreset = {
+        let form = form.clone();
+        let submitted = submitted.clone();
+        Callback::from(move |event: Event| {
+            event.prevent_default();
+            form.reset();
+            submitted.set(None);
+        })
+    };
+
+    html! {
+        <form {onsubmit} {onreset}>
+            <Field validation={form.validation("email")}>
+                <Label>{"Email"}</Label>
+                <Control>
+                    <Input input_type={InputType::Email} value={form.values().email.clone()}
+                        oninput={form.callback("email", |values: &mut SignUp, email| values.email = email)}
+                        onblur={form.onblur("email")} />
+                </Control>
+            </Field>
+
+            <Field validation={form.validation("password")}>
+                <Label>{"Password"}</Label>
+                <Control>
+                    <Input input_type={InputType::Password} value={form.values().password.clone()}
+                        oninput={form.callback("password", |values: &mut SignUp, password| values.password = password)}
+                        onblur={form.onblur("password")} />
+                </Control>
+                <Help>{"Use at least 8 characters"}</Help>
+            </Field>
+
+            <Field validation={form.validation("confirmation")}>
+                <Label>{"Confirm the password"}</Label>
+                <Control>
+                    <Input input_type={InputType::Password} value={form.values().confirmation.clone()}
+                        oninput={form.callback("confirmation", |values: &mut SignUp, confirmation| values.confirmation = confirmation)}
+                        onblur={form.onblur("confirmation")} />
+                </Control>
+            </Field>
+
+            <Field validation={form.validation("terms")}>
+                <Control>
+                    <Checkbox checked={form.values().terms}
+                        onchange={form.callback("terms", |values: &mut SignUp, terms| values.terms = terms)}>
+                        {" I agree to the terms and conditions"}
+                    </Checkbox>
+                </Control>
+            </Field>
+
+            <Buttons>
+                <Button color={Color::Primary} disabled={form.is_submitted() && !form.is_valid()}>
+                    {"Sign up"}
+                </Button>
+                <button class="button" type="reset" disabled={!form.is_dirty()}>{"Reset"}</button>
+            </Buttons>
+
+            if let Some(email) = &*submitted {
+                <Help color={Color::Success}>{format!("Signed up as {email}")}</Help>
+            }
+        </form>
+    }
+}
+
+fn main() {
+    yew::Renderer::<App>::new().render();
+}
@@ -0,0 +1,404 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Result,
+    Type,
+};
+
+/// The name of the attribute used to configure the generated form.
+const ATTRIBUTE: &str = "bulma_form";
+/// The label of the submit button, when none is given.
+const DEFAULT_SUBMIT: &str = "Submit";
+/// The primitive numeric types, rendered as number inputs.
+const NUMERIC_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64",
+];
+
+/// Defines the form element used to edit a field.
+enum FieldKind {
+    /// A text input, for `String` fields.
+    Text,
+    /// A number input, for primitive numeric fields.
+    Number,
+    /// A checkbox, for `bool` fields.
+    Checkbox,
+    /// A select, for fields whose options are given by a function.
+    Select(Path),
+}
+
+/// Holds the configuration of a single field of the form.
+struct FormField {
+    ident: Ident,
+    ty: Type,
+    kind: FieldKind,
+    label: LitStr,
+    placeholder: Option<LitStr>,
+    help: Option<LitStr>,
+    input_type: Option<Ident>,
+    required: bool,
+    validators: Vec<Path>,
+}
+
+impl FormField {
+    fn parse(field: &syn::Field) -> Result<Option<Self>> {
+        let ident = field
+            .ident
+            .clone()
+            .ok_or_else(|| Error::new(field.span(), "`BulmaForm` fields must be named"))?;
+        let mut skip = false;
+        let mut label = None;
+        let mut placeholder = None;
+        let mut help = None;
+        let mut input_type = None;
+        let mut options = None;
+        let mut required = false;
+        let mut validators = Vec::new();
+
+        for attr in form_attributes(&field.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("required") {
+                    required = true;
+                } else if meta.path.is_ident("label") {
+                    label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("placeholder") {
+                    placeholder = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("help") {
+                    help = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("input_type") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    input_type = Some(value.parse()?);
+                } else if meta.path.is_ident("options") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    options = Some(value.parse()?);
+                } else if meta.path.is_ident("validate") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    validators.push(value.parse()?);
+                } else {
+                    return Err(meta.error("unsupported `bulma_form` field attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        let kind =
+            match options {
+                Some(options) => FieldKind::Select(options),
+                None => match type_name(&field.ty).as_deref() {
+                    Some("String") => FieldKind::Text,
+                    Some("bool") => FieldKind::Checkbox,
+                    Some(name) if NUMERIC_TYPES.contains(&name) => FieldKind::Number,
+                    _ => return Err(Error::new(
+                        field.ty.span(),
+                        "`BulmaForm` fields which are not a `String`, a `bool` or a number need \
+                         their options, ie `#[bulma_form(options = \"path::to::options\")]`",
+                    )),
+                },
+            };
+        if input_type.is_some() && !matches!(kind, FieldKind::Text) {
+            return Err(Error::new(
+                field.span(),
+                "`input_type` can only be used on `String` fields",
+            ));
+        }
+        if required && !matches!(kind, FieldKind::Text | FieldKind::Checkbox) {
+            return Err(Error::new(
+                field.span(),
+                "`required` can only be used on `String` and `bool` fields",
+            ));
+        }
+        let label = label.unwrap_or_else(|| LitStr::new(&humanize(&ident), ident.span()));
+
+        Ok(Some(Self {
+            ident,
+            ty: field.ty.clone(),
+            kind,
+            label,
+            placeholder,
+            help,
+            input_type,
+            required,
+            validators,
+        }))
+    }
+
+    /// Generates the calls registering the validators of the field.
+    fn validators(&self, form: &Ident) -> TokenStream {
+        let ident = &self.ident;
+        let name = ident.to_string();
+        let required = if self.required {
+            let message = format!("{} is required", self.label.value());
+            let is_missing = match self.kind {
+                FieldKind::Checkbox => quote! { !values.#ident },
+                _ => quote! { values.#ident.trim().is_empty() },
+            };
+            quote! {
+                .with_validator(#name, |values: &#form| {
+                    if #is_missing {
+                        Err(#message.to_owned())
+                    } else {
+                        Ok(())
+                    }
+                })
+            }
+        } else {
+            quote! {}
+        };
+        let validators = self.validators.iter().map(|validator| {
+            quote! {
+                .with_validator(#name, |values: &#form| #validator(&values.#ident))
+            }
+        });
+
+        quote! {
+            #required
+            #(#validators)*
+        }
+    }
+
+    /// Generates the field element used to edit the field.
+    fn render(&self, form: &Ident) -> TokenStream {
+        let ident = &self.ident;
+        let ty = &self.ty;
+        let name = ident.to_string();
+        let label = &self.label;
+        let placeholder = self
+            .placeholder
+            .as_ref()
+            .map(|placeholder| quote! { Some(::yew::AttrValue::from(#placeholder)) })
+            .unwrap_or_else(|| quote! { None::<::yew::AttrValue> });
+        let help = self
+            .help
+            .as_ref()
+            .map(|help| quote! { <BulmaHelp>{ #help }</BulmaHelp> })
+            .unwrap_or_default();
+
+        let control = match &self.kind {
+            FieldKind::Text => {
+                let input_type = self
+                    .input_type
+                    .as_ref()
+                    .map(|input_type| quote! { BulmaInputType::#input_type })
+                    .unwrap_or_else(|| quote! { BulmaInputType::Text });
+                quote! {
+                    <BulmaInput input_type={#input_type} placeholder={#placeholder}
+                        value={form.values().#ident.clone()}
+                        oninput={form.callback(#name, |values: &mut #form, value: ::std::string::String| values.#ident = value)}
+                        onblur={form.onblur(#name)} />
+                }
+            }
+            FieldKind::Number => {
+                let message = format!("{} must be a number", self.label.value());
+                quote! {
+                    <BulmaInput input_type={BulmaInputType::Number} placeholder={#placeholder}
+                        value={form.text(#name).map(::std::borrow::ToOwned::to_owned)
+                            .unwrap_or_else(|| form.values().#ident.to_string())}
+                        oninput={form.text_callback(#name, |values: &mut #form, text: &str| {
+                            text.trim()
+                                .parse::<#ty>()
+                                .map(|value| values.#ident = value)
+                                .map_err(|_| #message.to_owned())
+                        })}
+                        onblur={form.onblur(#name)} />
+                }
+            }
+            FieldKind::Checkbox => quote! {
+                <BulmaCheckbox checked={form.values().#ident}
+                    onchange={form.callback(#name, |values: &mut #form, value: bool| values.#ident = value)}>
+                    { " " }{ #label }
+                </BulmaCheckbox>
+            },
+            FieldKind::Select(options) => quote! {
+                <BulmaSelect<#ty> options={#options()} placeholder={#placeholder}
+                    value={Some(form.values().#ident.clone())}
+                    onchange={form.callback(#name, |values: &mut #form, value: #ty| values.#ident = value)}
+                    onblur={form.onblur(#name)} />
+            },
+        };
+        let field_label = match self.kind {
+            FieldKind::Checkbox => quote! {},
+            _ => quote! { <BulmaLabel>{ #label }</BulmaLabel> },
+        };
+
+        quote! {
+            <BulmaField validation={form.validation(#name)}>
+                #field_label
+                <BulmaControl>
+                    #control
+                </BulmaControl>
+                #help
+            </BulmaField>
+        }
+    }
+}
+
+/// Generates the form component of the struct derived by `BulmaForm`.
+pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`BulmaForm` cannot be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`BulmaForm` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`BulmaForm` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut submit = LitStr::new(DEFAULT_SUBMIT, Span::call_site());
+    for attr in form_attributes(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("submit") {
+                submit = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `bulma_form` struct attribute"))
+            }
+        })?;
+    }
+
+    let mut form_fields = Vec::new();
+    for field in fields {
+        if let Some(form_field) = FormField::parse(field)? {
+            form_fields.push(form_field);
+        }
+    }
+
+    let vis = &input.vis;
+    let form = &input.ident;
+    let component = format_ident!("{}Form", form);
+    let properties = format_ident!("{}FormProperties", form);
+    let function = format_ident!("{}_form", snake_case(form));
+    let component_doc = format!(
+        "Yew form component editing a [`{form}`], generated by `BulmaForm`.\n\n\
+         Renders a Bulma field for each field of the [`{form}`] and passes \
+         the filled and validated [`{form}`] to the `onsubmit` callback."
+    );
+    let properties_doc = format!("Defines the properties of the [`{component}`] component.");
+    let validators = form_fields.iter().map(|field| field.validators(form));
+    let elements = form_fields.iter().map(|field| field.render(form));
+
+    Ok(quote! {
+        #[doc = #properties_doc]
+        #[derive(::yew::Properties, PartialEq)]
+        #vis struct #properties {
+            /// The values the form starts with, the default ones if not set.
+            #[prop_or_default]
+            pub initial: Option<#form>,
+            /// The callback receiving the values of the form, once it is
+            /// submitted and valid.
+            #[prop_or_default]
+            pub onsubmit: Option<::yew::Callback<#form>>,
+        }
+
+        #[doc = #component_doc]
+        #[::yew::function_component(#component)]
+        #vis fn #function(props: &#properties) -> ::yew::Html {
+            #[allow(unused_imports)]
+            use ::yew_and_bulma::{
+                elements::button::Button as BulmaButton,
+                form::{
+                    checkbox::Checkbox as BulmaCheckbox,
+                    general::{
+                        Control as BulmaControl, Field as BulmaField, Help as BulmaHelp,
+                        Label as BulmaLabel,
+                    },
+                    input::{Input as BulmaInput, InputType as BulmaInputType},
+                    select::Select as BulmaSelect,
+                },
+                helpers::color::Color as BulmaColor,
+            };
+
+            let initial = props.initial.clone();
+            let form = ::yew_and_bulma::form::state::use_form(move || {
+                ::yew_and_bulma::form::state::FormState::new(initial.unwrap_or_default())
+                    #(#validators)*
+            });
+            let onsubmit = {
+                let onsubmit = props.onsubmit.clone();
+                form.onsubmit(::yew::Callback::from(move |values: #form| {
+                    if let Some(onsubmit) = &onsubmit {
+                        onsubmit.emit(values);
+                    }
+                }))
+            };
+
+            ::yew::html! {
+                <form {onsubmit}>
+                    #(#elements)*
+                    <BulmaField>
+                        <BulmaControl>
+                            <BulmaButton color={BulmaColor::Primary}>{ #submit }</BulmaButton>
+                        </BulmaControl>
+                    </BulmaField>
+                </form>
+            }
+        }
+    })
+}
+
+/// Returns the attributes configuring the generated form.
+fn form_attributes(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE))
+}
+
+/// Returns the name of the given type, if it is a plain path.
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.arguments.is_empty())
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Turns a field name into a label, ie `first_name` into `First name`.
+fn humanize(ident: &Ident) -> String {
+    let words = ident.to_string().replace('_', " ");
+    let words = words.trim();
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Turns a struct name into snake case, ie `UserProfile` into `user_profile`.
+fn snake_case(ident: &Ident) -> String {
+    let mut snake = String::new();
+    for (index, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
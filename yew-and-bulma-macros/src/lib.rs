@@ -31,6 +31,11 @@
 ///
 /// [yew]: https://yew.rs/docs/concepts/function-components/properties
 mod attributes;
+/// Provides the implementation of the `BulmaForm` derive macro.
+///
+/// Defines the parsing of the `bulma_form` attributes and the generation of
+/// the form component and its properties.
+mod form;
 
 use core::panic;
 
@@ -54,6 +59,11 @@ use crate::attributes::BaseAttributes;
 /// struct MyProperties;
 /// ```
 ///
+/// Fields declared on the struct take precedence over the attributes with the
+/// same name, which allows a component to expose a differently typed
+/// callback, such as an `onselect` which receives the selected value instead
+/// of the raw event.
+///
 /// [events]: https://developer.mozilla.org/en-US/docs/Web/API/Element#events
 #[proc_macro_attribute]
 pub fn base_component_properties(_args: TokenStream, input: TokenStream) -> TokenStream {
@@ -66,8 +76,20 @@ pub fn base_component_properties(_args: TokenStream, input: TokenStream) -> Toke
 
     let expanded = match &mut struct_data.fields {
         syn::Fields::Named(fields) => {
+            let declared: Vec<_> = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+                .collect();
             for attr in BaseAttributes::default().attributes() {
-                fields.named.push(attr);
+                let is_declared = attr
+                    .ident
+                    .as_ref()
+                    .map(|ident| declared.contains(&ident.to_string()))
+                    .unwrap_or(false);
+                if !is_declared {
+                    fields.named.push(attr);
+                }
             }
 
             let struct_data = DeriveInput {
@@ -84,3 +106,77 @@ pub fn base_component_properties(_args: TokenStream, input: TokenStream) -> Toke
 
     expanded.into()
 }
+
+/// Generates a form component editing the struct it is derived for.
+///
+/// Generates a `{Struct}Form` [function component][fc], along with its
+/// `{Struct}FormProperties`, which renders a Bulma field for each field of
+/// the struct and passes the filled struct to its `onsubmit` callback once
+/// the form is submitted and valid. The form starts from the `initial`
+/// property, or from the default value of the struct. The generated code
+/// relies on the `yew` and `yew-and-bulma` crates, the latter re-exporting
+/// this macro as `yew_and_bulma::form::BulmaForm`.
+///
+/// The struct must implement `Clone`, `Default` and `PartialEq`, and each of
+/// its fields is rendered depending on its type:
+/// - `String` fields as inputs;
+/// - `bool` fields as checkboxes;
+/// - numeric fields as number inputs, which keep the text as typed and are
+///   invalid while it is not a number;
+/// - any other field, such as an enum, as a select, whose options are given
+///   by the `options` attribute.
+///
+/// The following attributes can be set on a field, using
+/// `#[bulma_form(...)]`:
+/// - `label = "..."`, the label of the field, the field name by default;
+/// - `placeholder = "..."`, the placeholder of the input or select;
+/// - `help = "..."`, the help text shown under the field;
+/// - `input_type = "..."`, the `InputType` variant of a `String` field, ie
+///   `"Email"` or `"Password"`;
+/// - `options = "path"`, the function returning the `Vec<(T, AttrValue)>`
+///   options of a select;
+/// - `required`, which requires a `String` field to not be blank and a `bool`
+///   field to be checked;
+/// - `validate = "path"`, a function receiving a reference to the field and
+///   returning a `Result<(), String>`, which can be repeated;
+/// - `skip`, which leaves the field out of the form.
+///
+/// The label of the submit button is set on the struct, using
+/// `#[bulma_form(submit = "...")]`, and defaults to `Submit`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::BulmaForm;
+///
+/// #[derive(Clone, Default, PartialEq, BulmaForm)]
+/// #[bulma_form(submit = "Save")]
+/// struct User {
+///     #[bulma_form(required, placeholder = "Jane Doe")]
+///     name: String,
+///     #[bulma_form(input_type = "Email", help = "Used to log in")]
+///     email: String,
+///     age: u32,
+///     admin: bool,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsubmit = Callback::from(|_user: User| {});
+///
+///     html! {
+///         <UserForm {onsubmit} />
+///     }
+/// }
+/// ```
+///
+/// [fc]: https://yew.rs/docs/concepts/function-components
+#[proc_macro_derive(BulmaForm, attributes(bulma_form))]
+pub fn bulma_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    form::derive(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
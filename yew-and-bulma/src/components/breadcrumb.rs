@@ -0,0 +1,480 @@
+use yew::{
+    function_component, html, html_nested, virtual_dom::VChild, AttrValue, Children,
+    ChildrenWithProps, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::Align,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the possible separators of the [Bulma breadcrumb component][bd].
+///
+/// Defines the possible separators placed between the items of a
+/// [Bulma breadcrumb component][bd]. When no separator is set, the default
+/// slash is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem, Separator};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb separator={Separator::Arrow}>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/#alternative-separators
+#[derive(PartialEq)]
+pub enum Separator {
+    Arrow,
+    Bullet,
+    Dot,
+    Succeeds,
+}
+
+impl From<&Separator> for String {
+    fn from(value: &Separator) -> Self {
+        match value {
+            Separator::Arrow => "has-arrow-separator".to_owned(),
+            Separator::Bullet => "has-bullet-separator".to_owned(),
+            Separator::Dot => "has-dot-separator".to_owned(),
+            Separator::Succeeds => "has-succeeds-separator".to_owned(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma breadcrumb component][bd].
+///
+/// Defines the properties of the breadcrumb component, based on the
+/// specification found in the [Bulma breadcrumb component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BreadcrumbProperties {
+    /// Sets the alignment of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the alignment of the items of the [Bulma breadcrumb component][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::breadcrumb::{Breadcrumb, BreadcrumbItem},
+    ///     elements::button::Align,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Breadcrumb align={Align::Center}>
+    ///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+    ///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#alignment
+    #[prop_or_default]
+    pub align: Option<Align>,
+    /// Sets the size of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the size of the [Bulma breadcrumb component][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::breadcrumb::{Breadcrumb, BreadcrumbItem},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Breadcrumb size={Size::Large}>
+    ///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+    ///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the separator of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the separator placed between the items of the
+    /// [Bulma breadcrumb component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem, Separator};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Breadcrumb separator={Separator::Bullet}>
+    ///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+    ///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#alternative-separators
+    #[prop_or_default]
+    pub separator: Option<Separator>,
+    /// The list of items found inside the [breadcrumb component][bd].
+    ///
+    /// Defines the items that will be found inside the
+    /// [Bulma breadcrumb component][bd] which will receive these properties.
+    /// The items can also be built from a path using
+    /// [`BreadcrumbItem::from_path`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    pub children: ChildrenWithProps<BreadcrumbItem>,
+}
+
+/// Yew implementation of the [Bulma breadcrumb component][bd].
+///
+/// Yew implementation of the breadcrumb component, based on the specification
+/// found in the [Bulma breadcrumb component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let path = vec![("Bulma", "/"), ("Documentation", "/documentation")];
+///
+///     html! {
+///         <Breadcrumb>
+///             { for BreadcrumbItem::from_path(path) }
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[function_component(Breadcrumb)]
+pub fn breadcrumb(props: &BreadcrumbProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("breadcrumb")
+        .with_custom_class(
+            &props
+                .align
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .separator
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <nav id={&props.id} {class} aria-label="breadcrumbs"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <ul>
+                { for props.children.iter() }
+            </ul>
+        </nav>
+    }
+}
+
+/// Defines the properties of the [Bulma breadcrumb item element][bd].
+///
+/// Defines the properties of an item found inside the breadcrumb component,
+/// based on the specification found in the
+/// [Bulma breadcrumb component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BreadcrumbItemProperties {
+    /// Sets the link of the [Bulma breadcrumb item element][bd].
+    ///
+    /// Sets the link of the [Bulma breadcrumb item element][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Breadcrumb>
+    ///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Whether or not the [breadcrumb item element][bd] is active.
+    ///
+    /// Whether or not the [Bulma breadcrumb item element][bd], which will
+    /// receive these properties, is active, meaning it points to the current
+    /// page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Breadcrumb>
+    ///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+    ///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    #[prop_or_default]
+    pub active: bool,
+    /// The list of elements found inside the [breadcrumb item element][bd].
+    ///
+    /// Defines the elements that will be found inside the link of the
+    /// [Bulma breadcrumb item element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma breadcrumb item element][bd].
+///
+/// Yew implementation of an item found inside the breadcrumb component, based
+/// on the specification found in the
+/// [Bulma breadcrumb component documentation][bd]. Active items are marked
+/// with `aria-current="page"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[function_component(BreadcrumbItem)]
+pub fn breadcrumb_item(props: &BreadcrumbItemProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(if props.active { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <li id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a href={&props.href} aria-current={props.active.then(|| "page")}>
+                { for props.children.iter() }
+            </a>
+        </li>
+    }
+}
+
+impl BreadcrumbItem {
+    /// Builds the items of a [Bulma breadcrumb component][bd] from a path.
+    ///
+    /// Builds one item for each `(label, href)` pair of the given path, in
+    /// order, marking the last one as active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let path = vec![
+    ///         ("Bulma", "/"),
+    ///         ("Documentation", "/documentation"),
+    ///         ("Breadcrumb", "/documentation/components/breadcrumb"),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <Breadcrumb>
+    ///             { for BreadcrumbItem::from_path(path) }
+    ///         </Breadcrumb>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    pub fn from_path<L, H>(path: Vec<(L, H)>) -> Vec<VChild<BreadcrumbItem>>
+    where
+        L: Into<AttrValue>,
+        H: Into<AttrValue>,
+    {
+        let last = path.len().saturating_sub(1);
+
+        path.into_iter()
+            .enumerate()
+            .map(|(index, (label, href))| {
+                let label: AttrValue = label.into();
+                let href: AttrValue = href.into();
+
+                html_nested! {
+                    <BreadcrumbItem {href} active={index == last}>{ label }</BreadcrumbItem>
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![], vec![] ; "empty path results in no items")]
+    #[test_case(vec![("Home", "/")], vec![("/", true)] ; "single item is active")]
+    #[test_case(vec![("Home", "/"), ("Docs", "/docs"), ("Panel", "/docs/panel")], vec![("/", false), ("/docs", false), ("/docs/panel", true)] ; "only the last item is active")]
+    fn from_path_marks_the_last_item_active(
+        path: Vec<(&'static str, &'static str)>,
+        expected_items: Vec<(&'static str, bool)>,
+    ) {
+        let items = BreadcrumbItem::from_path(path)
+            .iter()
+            .map(|item| {
+                (
+                    item.props.href.as_ref().map(|href| href.to_string()),
+                    item.props.active,
+                )
+            })
+            .collect::<Vec<_>>();
+        let expected_items = expected_items
+            .into_iter()
+            .map(|(href, active)| (Some(href.to_owned()), active))
+            .collect::<Vec<_>>();
+
+        assert_eq!(items, expected_items);
+    }
+}
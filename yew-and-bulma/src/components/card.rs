@@ -0,0 +1,881 @@
+use yew::{
+    function_component, html, html::ChildrenRenderer, virtual_dom::VChild, AttrValue, Callback,
+    Children, ChildrenWithProps, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        icon::Icon,
+        image::{Figure, Image},
+    },
+    utils::class::ClassBuilder,
+};
+
+/// Defines the properties of the [Bulma card component][bd].
+///
+/// Defines the properties of the card component, based on the specification
+/// found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{
+///     Card, CardContent, CardFooter, CardFooterItem, CardHeader,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardHeader>{"Component"}</CardHeader>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///                 <CardFooterItem href="#">{"Delete"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardProperties {
+    /// The list of elements found inside the [card component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma card component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: ChildrenRenderer<CardItem>,
+}
+
+/// Yew implementation of the [Bulma card component][bd].
+///
+/// Yew implementation of the card component, based on the specification found
+/// in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::card::{
+///         Card, CardContent, CardFooter, CardFooterItem, CardHeader, CardImage,
+///     },
+///     elements::image::{Figure, Image, Size},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardHeader>{"Component"}</CardHeader>
+///             <CardImage>
+///                 <Figure size={Size::Ratio4x3}>
+///                     <Image src="media/images/img.png" />
+///                 </Figure>
+///             </CardImage>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///                 <CardFooterItem href="#">{"Delete"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(Card)]
+pub fn card(props: &CardProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the possible types of children from a [Bulma card component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma card component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardContent, CardHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardHeader>{"Component"}</CardHeader>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[derive(Clone, PartialEq)]
+pub enum CardItem {
+    CardHeader(VChild<CardHeader>),
+    CardImage(VChild<CardImage>),
+    CardContent(VChild<CardContent>),
+    CardFooter(VChild<CardFooter>),
+}
+
+impl From<VChild<CardHeader>> for CardItem {
+    fn from(value: VChild<CardHeader>) -> Self {
+        CardItem::CardHeader(value)
+    }
+}
+
+impl From<VChild<CardImage>> for CardItem {
+    fn from(value: VChild<CardImage>) -> Self {
+        CardItem::CardImage(value)
+    }
+}
+
+impl From<VChild<CardContent>> for CardItem {
+    fn from(value: VChild<CardContent>) -> Self {
+        CardItem::CardContent(value)
+    }
+}
+
+impl From<VChild<CardFooter>> for CardItem {
+    fn from(value: VChild<CardFooter>) -> Self {
+        CardItem::CardFooter(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for CardItem {
+    fn into(self) -> Html {
+        match self {
+            CardItem::CardHeader(ch) => ch.into(),
+            CardItem::CardImage(ci) => ci.into(),
+            CardItem::CardContent(cc) => cc.into(),
+            CardItem::CardFooter(cf) => cf.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma card header element][bd].
+///
+/// Defines the properties of the card header element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardHeader>{"Component"}</CardHeader>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardHeaderProperties {
+    /// Whether or not the title of the [card header element][bd] is centered.
+    ///
+    /// Whether or not the title of the [Bulma card header element][bd], which
+    /// will receive these properties, should be centered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::card::{Card, CardHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Card>
+    ///             <CardHeader centered=true>{"Component"}</CardHeader>
+    ///         </Card>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    #[prop_or_default]
+    pub centered: bool,
+    /// Sets the icon of the [Bulma card header element][bd].
+    ///
+    /// Sets the icon shown inside a button at the end of the
+    /// [Bulma card header element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::card::{Card, CardHeader},
+    ///     elements::icon::Icon,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html_nested! {
+    ///         <Icon icon={html! { <i class="fas fa-angle-down"></i> }} />
+    ///     };
+    ///
+    ///     html! {
+    ///         <Card>
+    ///             <CardHeader {icon}>{"Component"}</CardHeader>
+    ///         </Card>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    #[prop_or_default]
+    pub icon: Option<VChild<Icon>>,
+    /// Sets the accessible label of the icon button of the [card header][bd].
+    ///
+    /// Sets the `aria-label` of the icon button of the
+    /// [Bulma card header element][bd] which will receive these properties.
+    /// Defaults to `more options`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::card::{Card, CardHeader},
+    ///     elements::icon::Icon,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html_nested! {
+    ///         <Icon icon={html! { <i class="fas fa-angle-down"></i> }} />
+    ///     };
+    ///
+    ///     html! {
+    ///         <Card>
+    ///             <CardHeader {icon} icon_label="expand">{"Component"}</CardHeader>
+    ///         </Card>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    #[prop_or(AttrValue::from("more options"))]
+    pub icon_label: AttrValue,
+    /// Sets the callback to be used when the icon of the [card header][bd] is clicked.
+    ///
+    /// Sets the callback to be used when the icon button of the
+    /// [Bulma card header element][bd], which will receive these properties,
+    /// is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::card::{Card, CardHeader},
+    ///     elements::icon::Icon,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html_nested! {
+    ///         <Icon icon={html! { <i class="fas fa-angle-down"></i> }} />
+    ///     };
+    ///     let oniconclick = Callback::from(|_: MouseEvent| {
+    ///         // Expand or collapse the card.
+    ///     });
+    ///
+    ///     html! {
+    ///         <Card>
+    ///             <CardHeader {icon} {oniconclick}>{"Component"}</CardHeader>
+    ///         </Card>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    #[prop_or_default]
+    pub oniconclick: Option<Callback<MouseEvent>>,
+    /// The list of elements found inside the [card header element][bd].
+    ///
+    /// Defines the elements that will be found inside the title of the
+    /// [Bulma card header element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma card header element][bd].
+///
+/// Yew implementation of the card header element, based on the specification
+/// found in the [Bulma card component documentation][bd]. The children are
+/// rendered as the title of the header, followed by the icon button, if an
+/// icon is given.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::card::{Card, CardHeader},
+///     elements::icon::Icon,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let icon = html_nested! {
+///         <Icon icon={html! { <i class="fas fa-angle-down"></i> }} />
+///     };
+///
+///     html! {
+///         <Card>
+///             <CardHeader {icon}>{"Component"}</CardHeader>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(CardHeader)]
+pub fn card_header(props: &CardHeaderProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card-header")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let title_class = ClassBuilder::default()
+        .with_custom_class("card-header-title")
+        .with_custom_class(if props.centered { "is-centered" } else { "" })
+        .build();
+
+    html! {
+        <header id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <p class={title_class}>
+                { for props.children.iter() }
+            </p>
+            if let Some(icon) = props.icon.clone() {
+                <button class="card-header-icon" aria-label={&props.icon_label}
+                    onclick={props.oniconclick.clone()}>
+                    { icon }
+                </button>
+            }
+        </header>
+    }
+}
+
+/// Defines the properties of the [Bulma card image element][bd].
+///
+/// Defines the properties of the card image element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::card::{Card, CardImage},
+///     elements::image::{Figure, Image, Size},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardImage>
+///                 <Figure size={Size::Ratio4x3}>
+///                     <Image src="media/images/img.png" />
+///                 </Figure>
+///             </CardImage>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardImageProperties {
+    /// The list of elements found inside the [card image element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma card image element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: ChildrenRenderer<CardImageItem>,
+}
+
+/// Yew implementation of the [Bulma card image element][bd].
+///
+/// Yew implementation of the card image element, based on the specification
+/// found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::card::{Card, CardImage},
+///     elements::image::{Figure, Image, Size},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardImage>
+///                 <Figure size={Size::Ratio4x3}>
+///                     <Image src="media/images/img.png" />
+///                 </Figure>
+///             </CardImage>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(CardImage)]
+pub fn card_image(props: &CardImageProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card-image")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the possible types of children from a [Bulma card image element][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma card image element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::card::{Card, CardImage},
+///     elements::image::Image,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardImage>
+///                 <Image src="media/images/img.png" />
+///             </CardImage>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[derive(Clone, PartialEq)]
+pub enum CardImageItem {
+    Figure(VChild<Figure>),
+    Image(VChild<Image>),
+}
+
+impl From<VChild<Figure>> for CardImageItem {
+    fn from(value: VChild<Figure>) -> Self {
+        CardImageItem::Figure(value)
+    }
+}
+
+impl From<VChild<Image>> for CardImageItem {
+    fn from(value: VChild<Image>) -> Self {
+        CardImageItem::Image(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for CardImageItem {
+    fn into(self) -> Html {
+        match self {
+            CardImageItem::Figure(f) => f.into(),
+            CardImageItem::Image(i) => i.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma card content element][bd].
+///
+/// Defines the properties of the card content element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardContentProperties {
+    /// The list of elements found inside the [card content element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma card content element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma card content element][bd].
+///
+/// Yew implementation of the card content element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(CardContent)]
+pub fn card_content(props: &CardContentProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card-content")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma card footer element][bd].
+///
+/// Defines the properties of the card footer element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardFooter, CardFooterItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///                 <CardFooterItem href="#">{"Delete"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardFooterProperties {
+    /// The list of items found inside the [card footer element][bd].
+    ///
+    /// Defines the items that will be found inside the
+    /// [Bulma card footer element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: ChildrenWithProps<CardFooterItem>,
+}
+
+/// Yew implementation of the [Bulma card footer element][bd].
+///
+/// Yew implementation of the card footer element, based on the specification
+/// found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardFooter, CardFooterItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///                 <CardFooterItem href="#">{"Delete"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(CardFooter)]
+pub fn card_footer(props: &CardFooterProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card-footer")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <footer id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </footer>
+    }
+}
+
+/// Defines the properties of the [Bulma card footer item element][bd].
+///
+/// Defines the properties of the card footer item element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardFooter, CardFooterItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardFooter>
+///                 <CardFooterItem>{"Read only"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardFooterItemProperties {
+    /// Sets the link of the [Bulma card footer item element][bd].
+    ///
+    /// Sets the link of the [Bulma card footer item element][bd] which will
+    /// receive these properties. When set, the item is rendered as an `<a>`
+    /// HTML tag, otherwise as a `<p>` HTML tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::card::{Card, CardFooter, CardFooterItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Card>
+    ///             <CardFooter>
+    ///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+    ///             </CardFooter>
+    ///         </Card>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// The list of elements found inside the [card footer item element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma card footer item element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/card/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma card footer item element][bd].
+///
+/// Yew implementation of the card footer item element, based on the
+/// specification found in the [Bulma card component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{Card, CardFooter, CardFooterItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///                 <CardFooterItem>{"Read only"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+#[function_component(CardFooterItem)]
+pub fn card_footer_item(props: &CardFooterItemProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("card-footer-item")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <@{(if props.href.is_some() { "a" } else { "p" }).to_string()} id={&props.id} {class} href={&props.href}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </@>
+    }
+}
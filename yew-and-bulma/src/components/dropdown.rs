@@ -0,0 +1,1100 @@
+use gloo_events::EventListener;
+use web_sys::Node;
+use yew::{
+    function_component, html,
+    html::{ChildrenRenderer, TargetCast},
+    use_context, use_effect_with_deps, use_node_ref, use_state,
+    virtual_dom::VChild,
+    AttrValue, Callback, Children, ContextProvider, Html, KeyboardEvent, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::utils::class::ClassBuilder;
+
+/// Shares the opened state between a [`Dropdown`] and its trigger.
+///
+/// Provided by the [`Dropdown`] so that the [`DropdownTrigger`] can open and
+/// close it, without having to know the type of the dropdown values.
+#[derive(Clone, PartialEq)]
+struct DropdownContext {
+    toggle: Callback<()>,
+}
+
+/// Shares the highlighted value and selection between a [`Dropdown`] and its
+/// items.
+///
+/// Provided by the [`Dropdown`] so that each [`DropdownItem`] knows whether
+/// it is highlighted by the keyboard navigation and can report its value when
+/// selected.
+#[derive(Clone, PartialEq)]
+struct DropdownItemContext<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    highlighted: Option<T>,
+    select: Callback<T>,
+}
+
+/// Defines the properties of the [Bulma dropdown component][bd].
+///
+/// Defines the properties of the dropdown component, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///                 <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownProperties<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Whether or not the [dropdown component][bd] opens on hover.
+    ///
+    /// Whether or not the [Bulma dropdown component][bd], which will receive
+    /// these properties, should open when hovered, in addition to being
+    /// opened by its trigger.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+    ///     elements::button::Button,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Dropdown<u32> hoverable=true>
+    ///             <DropdownTrigger>
+    ///                 <Button>{"Hover me"}</Button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu<u32>>
+    ///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+    ///             </DropdownMenu<u32>>
+    ///         </Dropdown<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#hoverable-or-toggable
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the [dropdown component][bd] is aligned to the right.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, should be aligned to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+    ///     elements::button::Button,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Dropdown<u32> right=true>
+    ///             <DropdownTrigger>
+    ///                 <Button>{"Right aligned"}</Button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu<u32>>
+    ///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+    ///             </DropdownMenu<u32>>
+    ///         </Dropdown<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#alignment
+    #[prop_or_default]
+    pub right: bool,
+    /// Whether or not the [dropdown component][bd] should open upwards.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, should appear above the trigger.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+    ///     elements::button::Button,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Dropdown<u32> up=true>
+    ///             <DropdownTrigger>
+    ///                 <Button>{"Dropup"}</Button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu<u32>>
+    ///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+    ///             </DropdownMenu<u32>>
+    ///         </Dropdown<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#dropup
+    #[prop_or_default]
+    pub up: bool,
+    /// Sets the callback to be used when an item of the [dropdown][bd] is selected.
+    ///
+    /// Sets the callback to be used when an item of the
+    /// [Bulma dropdown component][bd], which will receive these properties,
+    /// is selected, either by clicking it or by highlighting it with the
+    /// arrow keys and pressing `Enter`. The callback receives the value of
+    /// the selected item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+    ///     elements::button::Button,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onselect = Callback::from(|value: u32| {
+    ///         // Do something with the selected value.
+    ///     });
+    ///
+    ///     html! {
+    ///         <Dropdown<u32> {onselect}>
+    ///             <DropdownTrigger>
+    ///                 <Button>{"Pick a number"}</Button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu<u32>>
+    ///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+    ///                 <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+    ///             </DropdownMenu<u32>>
+    ///         </Dropdown<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub onselect: Option<Callback<T>>,
+    /// The list of elements found inside the [dropdown component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: ChildrenRenderer<DropdownElement<T>>,
+}
+
+/// Yew implementation of the [Bulma dropdown component][bd].
+///
+/// Yew implementation of the dropdown component, based on the specification
+/// found in the [Bulma dropdown component documentation][bd]. The dropdown
+/// keeps track of whether it is opened, which is toggled by its trigger and
+/// closed when clicking outside of it, pressing `Escape` or selecting an
+/// item. While it has the focus, the `ArrowDown` and `ArrowUp` keys move
+/// between its items and `Enter` selects the highlighted one.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{
+///         Dropdown, DropdownDivider, DropdownItem, DropdownMenu, DropdownTrigger,
+///     },
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///                 <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+///                 <DropdownDivider />
+///                 <DropdownItem<u32> value={3}>{"Three"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(Dropdown)]
+pub fn dropdown<T>(props: &DropdownProperties<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let node = use_node_ref();
+    let active = use_state(|| false);
+    let highlighted = use_state(|| None::<T>);
+
+    let close = {
+        let active = active.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |_| {
+            active.set(false);
+            highlighted.set(None);
+        })
+    };
+    let toggle = {
+        let active = active.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |_| {
+            active.set(!*active);
+            highlighted.set(None);
+        })
+    };
+    let select = {
+        let close = close.clone();
+        let onselect = props.onselect.clone();
+        Callback::from(move |value: T| {
+            close.emit(());
+            if let Some(onselect) = &onselect {
+                onselect.emit(value);
+            }
+        })
+    };
+
+    {
+        let node = node.clone();
+        let close = close.clone();
+        use_effect_with_deps(
+            move |active| {
+                let listener = if *active {
+                    web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| {
+                            EventListener::new(&document, "click", move |event| {
+                                let target = event.target_dyn_into::<Node>();
+                                let inside = node
+                                    .cast::<Node>()
+                                    .map(|root| root.contains(target.as_ref()))
+                                    .unwrap_or(false);
+                                if !inside {
+                                    close.emit(());
+                                }
+                            })
+                        })
+                } else {
+                    None
+                };
+
+                move || drop(listener)
+            },
+            *active,
+        );
+    }
+
+    let onkeydown = {
+        let values: Vec<T> = props
+            .children
+            .iter()
+            .flat_map(|child| child.values())
+            .collect();
+        let active = active.clone();
+        let highlighted = highlighted.clone();
+        let select = select.clone();
+        let close = close.clone();
+        let onkeydown = props.onkeydown.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            match event.key().as_str() {
+                key @ ("ArrowDown" | "ArrowUp") if !values.is_empty() => {
+                    event.prevent_default();
+                    let position = highlighted
+                        .as_ref()
+                        .and_then(|highlighted| values.iter().position(|v| v == highlighted));
+                    let next = next_position(position, values.len(), key == "ArrowDown");
+                    active.set(true);
+                    highlighted.set(Some(values[next].clone()));
+                }
+                "Enter" if *active => {
+                    if let Some(value) = (*highlighted).clone() {
+                        event.prevent_default();
+                        select.emit(value);
+                    }
+                }
+                "Escape" => close.emit(()),
+                _ => {}
+            }
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(event);
+            }
+        })
+    };
+
+    let context = DropdownContext { toggle };
+    let item_context = DropdownItemContext {
+        highlighted: (*highlighted).clone(),
+        select,
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown")
+        .with_custom_class(if *active { "is-active" } else { "" })
+        .with_custom_class(if props.hoverable { "is-hoverable" } else { "" })
+        .with_custom_class(if props.right { "is-right" } else { "" })
+        .with_custom_class(if props.up { "is-up" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<DropdownContext> {context}>
+            <ContextProvider<DropdownItemContext<T>> context={item_context}>
+                <div id={&props.id} {class} ref={node} {onkeydown}
+                    onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                    onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                    ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                    oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                    onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                    onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onsubmit={props.onsubmit.clone()}
+                    onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                    ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                    onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                    onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                    onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                    ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                    { for props.children.iter() }
+                </div>
+            </ContextProvider<DropdownItemContext<T>>>
+        </ContextProvider<DropdownContext>>
+    }
+}
+
+/// Computes the position of the next highlighted item of a dropdown.
+///
+/// Moves forwards or backwards from the currently highlighted position,
+/// wrapping around at both ends. When nothing is highlighted yet, moving
+/// forwards starts at the first item and moving backwards at the last one.
+fn next_position(position: Option<usize>, len: usize, forward: bool) -> usize {
+    match (position, forward) {
+        (Some(position), true) => (position + 1) % len,
+        (Some(position), false) => (position + len - 1) % len,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    }
+}
+
+/// Defines the possible types of children of a [Bulma dropdown component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma dropdown component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[derive(Clone, PartialEq)]
+pub enum DropdownElement<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    DropdownTrigger(VChild<DropdownTrigger>),
+    DropdownMenu(VChild<DropdownMenu<T>>),
+}
+
+impl<T> DropdownElement<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Returns the values of the items found inside this element, in order.
+    fn values(&self) -> Vec<T> {
+        match self {
+            DropdownElement::DropdownTrigger(_) => vec![],
+            DropdownElement::DropdownMenu(menu) => menu
+                .props
+                .children
+                .iter()
+                .filter_map(|item| match item {
+                    DropdownMenuItem::DropdownItem(item) => Some(item.props.value.clone()),
+                    DropdownMenuItem::DropdownDivider(_) => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T> From<VChild<DropdownTrigger>> for DropdownElement<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<DropdownTrigger>) -> Self {
+        DropdownElement::DropdownTrigger(value)
+    }
+}
+
+impl<T> From<VChild<DropdownMenu<T>>> for DropdownElement<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<DropdownMenu<T>>) -> Self {
+        DropdownElement::DropdownMenu(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Html> for DropdownElement<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn into(self) -> Html {
+        match self {
+            DropdownElement::DropdownTrigger(dt) => dt.into(),
+            DropdownElement::DropdownMenu(dm) => dm.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown trigger element][bd].
+///
+/// Defines the properties of the dropdown trigger element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownTriggerProperties {
+    /// The list of elements found inside the [dropdown trigger element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown trigger element][bd] which will receive these
+    /// properties, usually a [`crate::elements::button::Button`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown trigger element][bd].
+///
+/// Yew implementation of the dropdown trigger element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+/// When placed inside a [`Dropdown`], clicking it opens or closes the
+/// dropdown.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownTrigger)]
+pub fn dropdown_trigger(props: &DropdownTriggerProperties) -> Html {
+    let context = use_context::<DropdownContext>();
+    let onclick = {
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(context) = &context {
+                context.toggle.emit(());
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-trigger")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class} {onclick}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown menu element][bd].
+///
+/// Defines the properties of the dropdown menu element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownMenuProperties<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// The list of elements found inside the [dropdown menu element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown menu element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: ChildrenRenderer<DropdownMenuItem<T>>,
+}
+
+/// Yew implementation of the [Bulma dropdown menu element][bd].
+///
+/// Yew implementation of the dropdown menu element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownMenu)]
+pub fn dropdown_menu<T>(props: &DropdownMenuProperties<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-menu")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class} role="menu"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <div class="dropdown-content">
+                { for props.children.iter() }
+            </div>
+        </div>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma dropdown menu element][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma dropdown menu element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu<u32>>
+///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             <DropdownDivider />
+///             <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+///         </DropdownMenu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[derive(Clone, PartialEq)]
+pub enum DropdownMenuItem<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    DropdownItem(VChild<DropdownItem<T>>),
+    DropdownDivider(VChild<DropdownDivider>),
+}
+
+impl<T> From<VChild<DropdownItem<T>>> for DropdownMenuItem<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<DropdownItem<T>>) -> Self {
+        DropdownMenuItem::DropdownItem(value)
+    }
+}
+
+impl<T> From<VChild<DropdownDivider>> for DropdownMenuItem<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<DropdownDivider>) -> Self {
+        DropdownMenuItem::DropdownDivider(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Html> for DropdownMenuItem<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn into(self) -> Html {
+        match self {
+            DropdownMenuItem::DropdownItem(di) => di.into(),
+            DropdownMenuItem::DropdownDivider(dd) => dd.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown item element][bd].
+///
+/// Defines the properties of the dropdown item element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu<u32>>
+///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///         </DropdownMenu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownItemProperties<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Sets the value of the [Bulma dropdown item element][bd].
+    ///
+    /// Sets the value of the [Bulma dropdown item element][bd] which will
+    /// receive these properties. This value is passed to the `onselect`
+    /// callback of the [`Dropdown`] when the item is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownMenu<u32>>
+    ///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+    ///         </DropdownMenu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub value: T,
+    /// Sets the link of the [Bulma dropdown item element][bd].
+    ///
+    /// Sets the link of the [Bulma dropdown item element][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownMenu<u32>>
+    ///             <DropdownItem<u32> value={1} href="#one">{"One"}</DropdownItem<u32>>
+    ///         </DropdownMenu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Whether or not the [dropdown item element][bd] is active.
+    ///
+    /// Whether or not the [Bulma dropdown item element][bd], which will
+    /// receive these properties, is marked as active, for example to show
+    /// the currently selected value. Items highlighted through the keyboard
+    /// navigation are marked as active as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownMenu<u32>>
+    ///             <DropdownItem<u32> value={1} active=true>{"One"}</DropdownItem<u32>>
+    ///         </DropdownMenu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub active: bool,
+    /// The list of elements found inside the [dropdown item element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown item element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown item element][bd].
+///
+/// Yew implementation of the dropdown item element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu<u32>>
+///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///         </DropdownMenu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownItem)]
+pub fn dropdown_item<T>(props: &DropdownItemProperties<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let context = use_context::<DropdownItemContext<T>>();
+    let highlighted = context
+        .as_ref()
+        .and_then(|context| context.highlighted.as_ref())
+        .map(|highlighted| *highlighted == props.value)
+        .unwrap_or(false);
+    let onclick = {
+        let value = props.value.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(context) = &context {
+                context.select.emit(value.clone());
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-item")
+        .with_custom_class(if props.active || highlighted {
+            "is-active"
+        } else {
+            ""
+        })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <a id={&props.id} {class} href={&props.href} {onclick}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </a>
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown divider element][bd].
+///
+/// Defines the properties of the dropdown divider element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu<u32>>
+///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             <DropdownDivider />
+///             <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+///         </DropdownMenu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownDividerProperties {}
+
+/// Yew implementation of the [Bulma dropdown divider element][bd].
+///
+/// Yew implementation of the dropdown divider element, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu<u32>>
+///             <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             <DropdownDivider />
+///             <DropdownItem<u32> value={2}>{"Two"}</DropdownItem<u32>>
+///         </DropdownMenu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownDivider)]
+pub fn dropdown_divider(props: &DropdownDividerProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-divider")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <hr id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(None, 3, true, 0 ; "forward without highlight starts at the first item")]
+    #[test_case(None, 3, false, 2 ; "backward without highlight starts at the last item")]
+    #[test_case(Some(0), 3, true, 1 ; "forward moves to the next item")]
+    #[test_case(Some(1), 3, false, 0 ; "backward moves to the previous item")]
+    #[test_case(Some(2), 3, true, 0 ; "forward wraps around to the first item")]
+    #[test_case(Some(0), 3, false, 2 ; "backward wraps around to the last item")]
+    fn next_position_moves_and_wraps(
+        position: Option<usize>,
+        len: usize,
+        forward: bool,
+        expected_position: usize,
+    ) {
+        let next = next_position(position, len, forward);
+
+        assert_eq!(next, expected_position);
+    }
+}
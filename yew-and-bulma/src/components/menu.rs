@@ -0,0 +1,833 @@
+use yew::{
+    function_component, html, html::ChildrenRenderer, use_context, use_state, virtual_dom::VChild,
+    AttrValue, Callback, Children, ChildrenWithProps, ContextProvider, Html, MouseEvent,
+    Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{elements::icon::Icon, utils::class::ClassBuilder};
+
+/// Shares the selected item and the selection callback between a [`Menu`]
+/// and the [`MenuItem`] elements found inside it.
+#[derive(Clone, PartialEq)]
+struct MenuContext<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    selected: Option<K>,
+    select: Callback<K>,
+}
+
+/// Defines the properties of the [Bulma menu component][bd].
+///
+/// Defines the properties of the menu component, based on the specification
+/// found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32> selected={1}>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///                 <MenuItem<u32> value={2} label="Customers" />
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the selected item of the [menu component][bd].
+    ///
+    /// Sets the key of the selected item of the [Bulma menu component][bd]
+    /// which will receive these properties. The [`MenuItem`] whose value
+    /// matches this key is marked as active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Menu<&'static str> selected="customers">
+    ///             <MenuList<&'static str>>
+    ///                 <MenuItem<&'static str> value="dashboard" label="Dashboard" />
+    ///                 <MenuItem<&'static str> value="customers" label="Customers" />
+    ///             </MenuList<&'static str>>
+    ///         </Menu<&'static str>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub selected: Option<K>,
+    /// Sets the callback to be used when an item of the [menu component][bd] is clicked.
+    ///
+    /// Sets the callback to be used when an item of the
+    /// [Bulma menu component][bd], which will receive these properties, is
+    /// clicked. The callback receives the value of the clicked item, which
+    /// should be used to update the `selected` property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let selected = use_state(|| 1);
+    ///     let onselect = {
+    ///         let selected = selected.clone();
+    ///         Callback::from(move |value: u32| selected.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Menu<u32> selected={*selected} {onselect}>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Dashboard" />
+    ///                 <MenuItem<u32> value={2} label="Customers" />
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub onselect: Option<Callback<K>>,
+    /// The list of elements found inside the [menu component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma menu component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    pub children: ChildrenRenderer<MenuElement<K>>,
+}
+
+/// Yew implementation of the [Bulma menu component][bd].
+///
+/// Yew implementation of the menu component, based on the specification found
+/// in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let selected = use_state(|| 1);
+///     let onselect = {
+///         let selected = selected.clone();
+///         Callback::from(move |value: u32| selected.set(value))
+///     };
+///
+///     html! {
+///         <Menu<u32> selected={*selected} {onselect}>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///                 <MenuItem<u32> value={2} label="Team">
+///                     <MenuList<u32>>
+///                         <MenuItem<u32> value={3} label="Members" />
+///                         <MenuItem<u32> value={4} label="Plugins" />
+///                     </MenuList<u32>>
+///                 </MenuItem<u32>>
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(Menu)]
+pub fn menu<K>(props: &MenuProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let select = {
+        let onselect = props.onselect.clone();
+        Callback::from(move |value: K| {
+            if let Some(onselect) = &onselect {
+                onselect.emit(value);
+            }
+        })
+    };
+    let context = MenuContext {
+        selected: props.selected.clone(),
+        select,
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("menu")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<MenuContext<K>> {context}>
+            <aside id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </aside>
+        </ContextProvider<MenuContext<K>>>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma menu component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma menu component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[derive(Clone, PartialEq)]
+pub enum MenuElement<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    MenuLabel(VChild<MenuLabel>),
+    MenuList(VChild<MenuList<K>>),
+}
+
+impl<K> From<VChild<MenuLabel>> for MenuElement<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<MenuLabel>) -> Self {
+        MenuElement::MenuLabel(value)
+    }
+}
+
+impl<K> From<VChild<MenuList<K>>> for MenuElement<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<MenuList<K>>) -> Self {
+        MenuElement::MenuList(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<K> Into<Html> for MenuElement<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn into(self) -> Html {
+        match self {
+            MenuElement::MenuLabel(ml) => ml.into(),
+            MenuElement::MenuList(ml) => ml.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma menu label element][bd].
+///
+/// Defines the properties of the menu label element, based on the
+/// specification found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuLabel};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuLabel>{"General"}</MenuLabel>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuLabelProperties {
+    /// The list of elements found inside the [menu label element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma menu label element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma menu label element][bd].
+///
+/// Yew implementation of the menu label element, based on the specification
+/// found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuLabel};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuLabel>{"General"}</MenuLabel>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(MenuLabel)]
+pub fn menu_label(props: &MenuLabelProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("menu-label")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
+
+/// Defines the properties of the [Bulma menu list element][bd].
+///
+/// Defines the properties of the menu list element, based on the
+/// specification found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuListProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// The list of items found inside the [menu list element][bd].
+    ///
+    /// Defines the items that will be found inside the
+    /// [Bulma menu list element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    pub children: ChildrenWithProps<MenuItem<K>>,
+}
+
+/// Yew implementation of the [Bulma menu list element][bd].
+///
+/// Yew implementation of the menu list element, based on the specification
+/// found in the [Bulma menu component documentation][bd]. Menu lists can be
+/// nested inside a [`MenuItem`] to create sub-lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Team">
+///                     <MenuList<u32>>
+///                         <MenuItem<u32> value={2} label="Members" />
+///                     </MenuList<u32>>
+///                 </MenuItem<u32>>
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(MenuList)]
+pub fn menu_list<K>(props: &MenuListProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let class = ClassBuilder::default()
+        .with_custom_class("menu-list")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ul id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </ul>
+    }
+}
+
+/// Defines the properties of the [Bulma menu item element][bd].
+///
+/// Defines the properties of an item of a menu list, based on the
+/// specification found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32>>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuItemProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the [Bulma menu item element][bd].
+    ///
+    /// Sets the key of the [Bulma menu item element][bd] which will receive
+    /// these properties. The item is active when this key is equal to the
+    /// `selected` key of its [`Menu`], and it is passed to their `onselect`
+    /// callback when the item is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Menu<u32> selected={1}>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Dashboard" />
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    pub value: K,
+    /// Sets the label of the [Bulma menu item element][bd].
+    ///
+    /// Sets the label of the [Bulma menu item element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Dashboard" />
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    pub label: AttrValue,
+    /// Sets the link of the [Bulma menu item element][bd].
+    ///
+    /// Sets the link of the [Bulma menu item element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Dashboard" href="#dashboard" />
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the icon of the [Bulma menu item element][bd].
+    ///
+    /// Sets the icon shown before the label of the [Bulma menu item element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::menu::{Menu, MenuItem, MenuList},
+    ///     elements::icon::Icon,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html_nested! {
+    ///         <Icon icon={html! { <i class="fas fa-home"></i> }} />
+    ///     };
+    ///
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Dashboard" {icon} />
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub icon: Option<VChild<Icon>>,
+    /// Whether or not the sub-lists of the [menu item element][bd] can be collapsed.
+    ///
+    /// Whether or not the sub-lists of the [Bulma menu item element][bd],
+    /// which will receive these properties, can be collapsed and expanded by
+    /// clicking the item. Clicking a collapsible item only collapses or
+    /// expands it, without selecting it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Team" collapsible=true>
+    ///                     <MenuList<u32>>
+    ///                         <MenuItem<u32> value={2} label="Members" />
+    ///                     </MenuList<u32>>
+    ///                 </MenuItem<u32>>
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub collapsible: bool,
+    /// Sets whether or not the sub-lists of the [menu item element][bd] are collapsed.
+    ///
+    /// Sets whether or not the sub-lists of the [Bulma menu item element][bd],
+    /// which will receive these properties, are collapsed. Only used when the
+    /// item is `collapsible`. When set, the item only collapses and expands
+    /// through this property, usually from the `oncollapsechange` callback.
+    /// Otherwise, the item keeps track of its own state, starting expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let collapsed = use_state(|| true);
+    ///     let oncollapsechange = {
+    ///         let collapsed = collapsed.clone();
+    ///         Callback::from(move |value| collapsed.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Team" collapsible=true collapsed={*collapsed} {oncollapsechange}>
+    ///                     <MenuList<u32>>
+    ///                         <MenuItem<u32> value={2} label="Members" />
+    ///                     </MenuList<u32>>
+    ///                 </MenuItem<u32>>
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub collapsed: Option<bool>,
+    /// Sets the callback to be used when the [menu item element][bd] is collapsed or expanded.
+    ///
+    /// Sets the callback to be used when the collapsible
+    /// [Bulma menu item element][bd], which will receive these properties, is
+    /// clicked in order to collapse or expand its sub-lists. The callback
+    /// receives whether or not the item should be collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let collapsed = use_state(|| false);
+    ///     let oncollapsechange = {
+    ///         let collapsed = collapsed.clone();
+    ///         Callback::from(move |value| collapsed.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Menu<u32>>
+    ///             <MenuList<u32>>
+    ///                 <MenuItem<u32> value={1} label="Team" collapsible=true collapsed={*collapsed} {oncollapsechange}>
+    ///                     <MenuList<u32>>
+    ///                         <MenuItem<u32> value={2} label="Members" />
+    ///                     </MenuList<u32>>
+    ///                 </MenuItem<u32>>
+    ///             </MenuList<u32>>
+    ///         </Menu<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub oncollapsechange: Option<Callback<bool>>,
+    /// The list of sub-lists found inside the [menu item element][bd].
+    ///
+    /// Defines the nested menu lists that will be found inside the
+    /// [Bulma menu item element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub children: ChildrenWithProps<MenuList<K>>,
+}
+
+/// Yew implementation of the [Bulma menu item element][bd].
+///
+/// Yew implementation of an item of a menu list, based on the specification
+/// found in the [Bulma menu component documentation][bd]. The item renders
+/// a link, marked as active when its value is the selected key of the
+/// [`Menu`], followed by its nested sub-lists, unless they are collapsed.
+/// Clicking the item selects it, unless it is collapsible, in which case it
+/// collapses or expands its sub-lists instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32> selected={2}>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Team" collapsible=true>
+///                     <MenuList<u32>>
+///                         <MenuItem<u32> value={2} label="Members" />
+///                         <MenuItem<u32> value={3} label="Plugins" />
+///                     </MenuList<u32>>
+///                 </MenuItem<u32>>
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(MenuItem)]
+pub fn menu_item<K>(props: &MenuItemProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let context = use_context::<MenuContext<K>>();
+    let own_collapsed = use_state(|| false);
+    let collapsed = props.collapsible && props.collapsed.unwrap_or(*own_collapsed);
+    let active = context
+        .as_ref()
+        .and_then(|context| context.selected.as_ref())
+        .map(|selected| *selected == props.value)
+        .unwrap_or(false);
+    let onclick = {
+        let value = props.value.clone();
+        let collapsible = props.collapsible;
+        let controlled = props.collapsed.is_some();
+        let oncollapsechange = props.oncollapsechange.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if collapsible {
+                if !controlled {
+                    own_collapsed.set(!collapsed);
+                }
+                if let Some(oncollapsechange) = &oncollapsechange {
+                    oncollapsechange.emit(!collapsed);
+                }
+            } else if let Some(context) = &context {
+                context.select.emit(value.clone());
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let link_class = ClassBuilder::default()
+        .with_custom_class(if active { "is-active" } else { "" })
+        .build();
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <li id={&props.id} {class}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a class={link_class} href={&props.href} {onclick}>
+                if let Some(icon) = props.icon.clone() {
+                    { icon }
+                }
+                { &props.label }
+            </a>
+            if !collapsed {
+                { for props.children.iter() }
+            }
+        </li>
+    }
+}
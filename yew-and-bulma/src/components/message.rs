@@ -0,0 +1,491 @@
+use yew::{
+    function_component, html, html::ChildrenRenderer, use_context, virtual_dom::VChild, Callback,
+    Children, ContextProvider, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::delete::Delete,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Shares the dismiss callback between a [`Message`] and its header.
+#[derive(Clone, PartialEq)]
+struct MessageContext {
+    dismiss: Option<Callback<()>>,
+}
+
+/// Defines the properties of the [Bulma message component][bd].
+///
+/// Defines the properties of the message component, based on the
+/// specification found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader>{"Hello World"}</MessageHeader>
+///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageProperties {
+    /// Sets the color of the [Bulma message component][bd].
+    ///
+    /// Sets the color of the [Bulma message component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::message::{Message, MessageBody},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message color={Color::Info}>
+    ///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma message component][bd].
+    ///
+    /// Sets the size of the [Bulma message component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::message::{Message, MessageBody},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message size={Size::Small}>
+    ///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [message component][bd] is hidden.
+    ///
+    /// Whether or not the [Bulma message component][bd], which will receive
+    /// these properties, should be hidden, while being kept in the page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageBody};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message hidden=true>
+    ///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or_default]
+    pub hidden: bool,
+    /// Sets the callback to be used when the [message][bd] is dismissed.
+    ///
+    /// Sets the callback to be used when the delete button found in the
+    /// header of the [Bulma message component][bd], which will receive these
+    /// properties, is clicked. The delete button is only rendered when this
+    /// callback is set. The message does not hide itself, so the callback
+    /// should be used to either hide it, using the `hidden` property, or to
+    /// stop rendering it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let hidden = use_state(|| false);
+    ///     let ondismiss = {
+    ///         let hidden = hidden.clone();
+    ///         Callback::from(move |_| hidden.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Message hidden={*hidden} {ondismiss}>
+    ///             <MessageHeader>{"Hello World"}</MessageHeader>
+    ///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<()>>,
+    /// The list of elements found inside the [message component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: ChildrenRenderer<MessageItem>,
+}
+
+/// Yew implementation of the [Bulma message component][bd].
+///
+/// Yew implementation of the message component, based on the specification
+/// found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::message::{Message, MessageBody, MessageHeader},
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let visible = use_state(|| true);
+///     let ondismiss = {
+///         let visible = visible.clone();
+///         Callback::from(move |_| visible.set(false))
+///     };
+///
+///     html! {
+///         if *visible {
+///             <Message color={Color::Danger} {ondismiss}>
+///                 <MessageHeader>{"Error"}</MessageHeader>
+///                 <MessageBody>{"Something went wrong."}</MessageBody>
+///             </Message>
+///         }
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(Message)]
+pub fn message(props: &MessageProperties) -> Html {
+    let context = MessageContext {
+        dismiss: props.ondismiss.clone(),
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("message")
+        .with_color(props.color)
+        .with_custom_class(&size)
+        .with_custom_class(if props.hidden { "is-hidden" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<MessageContext> {context}>
+            <article id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </article>
+        </ContextProvider<MessageContext>>
+    }
+}
+
+/// Defines the possible types of children from a [Bulma message component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma message component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader>{"Hello World"}</MessageHeader>
+///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[derive(Clone, PartialEq)]
+pub enum MessageItem {
+    MessageHeader(VChild<MessageHeader>),
+    MessageBody(VChild<MessageBody>),
+}
+
+impl From<VChild<MessageHeader>> for MessageItem {
+    fn from(value: VChild<MessageHeader>) -> Self {
+        MessageItem::MessageHeader(value)
+    }
+}
+
+impl From<VChild<MessageBody>> for MessageItem {
+    fn from(value: VChild<MessageBody>) -> Self {
+        MessageItem::MessageBody(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for MessageItem {
+    fn into(self) -> Html {
+        match self {
+            MessageItem::MessageHeader(mh) => mh.into(),
+            MessageItem::MessageBody(mb) => mb.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma message header element][bd].
+///
+/// Defines the properties of the message header element, based on the
+/// specification found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader>{"Hello World"}</MessageHeader>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageHeaderProperties {
+    /// The list of elements found inside the [message header element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message header element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma message header element][bd].
+///
+/// Yew implementation of the message header element, based on the
+/// specification found in the [Bulma message component documentation][bd].
+/// When placed inside a [`Message`] which has an `ondismiss` callback, a
+/// delete button firing it is rendered after the children.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message ondismiss={Callback::from(|_| {})}>
+///             <MessageHeader>{"Hello World"}</MessageHeader>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(MessageHeader)]
+pub fn message_header(props: &MessageHeaderProperties) -> Html {
+    let ondelete = use_context::<MessageContext>()
+        .and_then(|context| context.dismiss)
+        .map(|dismiss| {
+            Callback::from(move |event: MouseEvent| {
+                event.prevent_default();
+                dismiss.emit(());
+            })
+        });
+    let class = ClassBuilder::default()
+        .with_custom_class("message-header")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <p>{ for props.children.iter() }</p>
+            if ondelete.is_some() {
+                <Delete onclick={ondelete} />
+            }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma message body element][bd].
+///
+/// Defines the properties of the message body element, based on the
+/// specification found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageBodyProperties {
+    /// The list of elements found inside the [message body element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message body element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma message body element][bd].
+///
+/// Yew implementation of the message body element, based on the
+/// specification found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(MessageBody)]
+pub fn message_body(props: &MessageBodyProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("message-body")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,274 @@
+/// Provides utilities for creating [breadcrumb components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma breadcrumb components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, BreadcrumbItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///             <BreadcrumbItem active=true>{"Documentation"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+pub mod breadcrumb;
+/// Provides utilities for creating [card components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma card components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::card::{
+///     Card, CardContent, CardFooter, CardFooterItem, CardHeader,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Card>
+///             <CardHeader>{"Component"}</CardHeader>
+///             <CardContent>{"Lorem ipsum dolor sit amet."}</CardContent>
+///             <CardFooter>
+///                 <CardFooterItem href="#">{"Save"}</CardFooterItem>
+///             </CardFooter>
+///         </Card>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/card/
+pub mod card;
+/// Provides utilities for creating [dropdown components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma dropdown components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown<u32>>
+///             <DropdownTrigger>
+///                 <Button>{"Pick a number"}</Button>
+///             </DropdownTrigger>
+///             <DropdownMenu<u32>>
+///                 <DropdownItem<u32> value={1}>{"One"}</DropdownItem<u32>>
+///             </DropdownMenu<u32>>
+///         </Dropdown<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+pub mod dropdown;
+/// Provides utilities for creating [menu components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma menu components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menu<u32> selected={1}>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList<u32>>
+///                 <MenuItem<u32> value={1} label="Dashboard" />
+///                 <MenuItem<u32> value={2} label="Customers" />
+///             </MenuList<u32>>
+///         </Menu<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+pub mod menu;
+/// Provides utilities for creating [message components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma message components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader>{"Hello World"}</MessageHeader>
+///             <MessageBody>{"Lorem ipsum dolor sit amet."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+pub mod message;
+/// Provides utilities for creating [modal components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma modal components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{
+///     Modal, ModalCard, ModalCardBody, ModalCardFoot, ModalCardHead,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>{"Modal actions"}</ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+pub mod modal;
+/// Provides utilities for creating [navbar components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma navbar components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarEnd, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+///             </NavbarBrand>
+///
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///                 <NavbarEnd>
+///                     <NavbarItem>{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+pub mod navbar;
+/// Provides utilities for creating [pagination components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma pagination components][bd] in Yew, as well as the function used to
+/// compute the pages which are shown.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Pagination current={46} total_pages={86} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+pub mod pagination;
+/// Provides utilities for creating [panel components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma panel components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelBlock active=true>{"bulma"}</PanelBlock>
+///             <PanelBlock>{"marksheet"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+pub mod panel;
+/// Provides utilities for creating [tabs components][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma tabs components][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///
+///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+///             <TabPanel<u32> value={2}>{"Some music"}</TabPanel<u32>>
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+pub mod tabs;
@@ -0,0 +1,920 @@
+use gloo_events::EventListener;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use yew::{
+    function_component, html, html::ChildrenRenderer, use_context, use_effect_with_deps,
+    use_node_ref, virtual_dom::VChild, Callback, Children, ContextProvider, Html, KeyboardEvent,
+    MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{elements::delete::Delete, utils::class::ClassBuilder};
+
+/// Shares the close trigger between a [`Modal`] and its children.
+///
+/// Provided by the [`Modal`] so that the [`ModalCardHead`] can render a
+/// [`Delete`] button which closes the modal.
+#[derive(Clone, PartialEq)]
+struct ModalContext {
+    close: Callback<()>,
+    close_button: bool,
+}
+
+/// Defines the properties of the [Bulma modal component][bd].
+///
+/// Defines the properties of the modal component, based on the specification
+/// found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalContent>{"Any content you want."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalProperties {
+    /// Whether or not the [modal component][bd] is open.
+    ///
+    /// Whether or not the [Bulma modal component][bd], which will receive
+    /// these properties, is open. The modal only opens and closes through this
+    /// property, its close triggers only emitting `onclose`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let open = use_state(|| true);
+    ///     let onclose = {
+    ///         let open = open.clone();
+    ///         Callback::from(move |_| open.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Modal open={*open} {onclose}>
+    ///             <ModalContent>{"Any content you want."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    pub open: bool,
+    /// Sets the callback to be used when the [modal component][bd] is closed.
+    ///
+    /// Sets the callback to be used when the [Bulma modal component][bd],
+    /// which will receive these properties, is closed by clicking its
+    /// background, pressing the `Escape` key or clicking its close button.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onclose = Callback::from(|_| {
+    ///         // Do something when the modal closes.
+    ///     });
+    ///
+    ///     html! {
+    ///         <Modal open=true {onclose}>
+    ///             <ModalContent>{"Any content you want."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+    /// Whether or not clicking the background closes the [modal component][bd].
+    ///
+    /// Whether or not the [Bulma modal component][bd], which will receive
+    /// these properties, should close when its background is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal open=true close_on_background=false>
+    ///             <ModalContent>{"Any content you want."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or(true)]
+    pub close_on_background: bool,
+    /// Whether or not pressing `Escape` closes the [modal component][bd].
+    ///
+    /// Whether or not the [Bulma modal component][bd], which will receive
+    /// these properties, should close when the `Escape` key is pressed while
+    /// it is open, wherever the focus is. The modal also focuses itself
+    /// whenever it opens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal open=true close_on_escape=false>
+    ///             <ModalContent>{"Any content you want."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// Whether or not the [modal component][bd] should have a close button.
+    ///
+    /// Whether or not the [Bulma modal component][bd], which will receive
+    /// these properties, should render a close button. For a [`ModalCard`],
+    /// this is a [`Delete`] inside its [`ModalCardHead`], otherwise it is
+    /// the `modal-close` button in the top right corner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal open=true close_button=false>
+    ///             <ModalContent>{"Any content you want."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or(true)]
+    pub close_button: bool,
+    /// The list of elements found inside the [modal component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    pub children: ChildrenRenderer<ModalItem>,
+}
+
+/// Yew implementation of the [Bulma modal component][bd].
+///
+/// Yew implementation of the modal component, based on the specification
+/// found in the [Bulma modal component documentation][bd]. Keep in mind that
+/// Bulma recommends adding the `is-clipped` class to the `<html>` element
+/// while a modal is open, which is outside of the control of the modal.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{
+///     Modal, ModalCard, ModalCardBody, ModalCardFoot, ModalCardHead,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>{"Modal actions"}</ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(Modal)]
+pub fn modal(props: &ModalProperties) -> Html {
+    let node = use_node_ref();
+    let open = props.open;
+    {
+        let node = node.clone();
+        use_effect_with_deps(
+            move |open| {
+                if *open {
+                    if let Some(element) = node.cast::<HtmlElement>() {
+                        let _ = element.focus();
+                    }
+                }
+                || ()
+            },
+            open,
+        );
+    }
+
+    let close = {
+        let onclose = props.onclose.clone();
+        Callback::from(move |_| {
+            if let Some(onclose) = &onclose {
+                onclose.emit(());
+            }
+        })
+    };
+    {
+        let close = close.clone();
+        use_effect_with_deps(
+            move |(listen, _)| {
+                let listener = if *listen {
+                    web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| {
+                            EventListener::new(&document, "keydown", move |event| {
+                                let escape = event
+                                    .dyn_ref::<KeyboardEvent>()
+                                    .map_or(false, |event| event.key() == "Escape");
+                                if escape {
+                                    close.emit(());
+                                }
+                            })
+                        })
+                } else {
+                    None
+                };
+
+                move || drop(listener)
+            },
+            (open && props.close_on_escape, props.onclose.clone()),
+        );
+    }
+    let onbackgroundclick = props
+        .close_on_background
+        .then(|| close.reform(|_: MouseEvent| ()));
+    let has_content = props
+        .children
+        .iter()
+        .any(|child| matches!(child, ModalItem::ModalContent(_)));
+    let context = ModalContext {
+        close: close.clone(),
+        close_button: props.close_button,
+    };
+
+    let class = ClassBuilder::default()
+        .with_custom_class("modal")
+        .with_custom_class(if open { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<ModalContext> {context}>
+            <div id={&props.id} {class} ref={node} tabindex="-1"
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                <div class="modal-background" onclick={onbackgroundclick}></div>
+                { for props.children.iter() }
+                if props.close_button && has_content {
+                    <button class="modal-close is-large" aria-label="close"
+                        onclick={close.reform(|_: MouseEvent| ())}></button>
+                }
+            </div>
+        </ContextProvider<ModalContext>>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma modal component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma modal component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalContent>{"Any content you want."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[derive(Clone, PartialEq)]
+pub enum ModalItem {
+    ModalContent(VChild<ModalContent>),
+    ModalCard(VChild<ModalCard>),
+}
+
+impl From<VChild<ModalContent>> for ModalItem {
+    fn from(value: VChild<ModalContent>) -> Self {
+        ModalItem::ModalContent(value)
+    }
+}
+
+impl From<VChild<ModalCard>> for ModalItem {
+    fn from(value: VChild<ModalCard>) -> Self {
+        ModalItem::ModalCard(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for ModalItem {
+    fn into(self) -> Html {
+        match self {
+            ModalItem::ModalContent(mc) => mc.into(),
+            ModalItem::ModalCard(mc) => mc.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma modal content element][bd].
+///
+/// Defines the properties of the modal content element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalContent>{"Any content you want."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalContentProperties {
+    /// The list of elements found inside the [modal content element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal content element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal content element][bd].
+///
+/// Yew implementation of the modal content element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalContent>{"Any content you want."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(ModalContent)]
+pub fn modal_content(props: &ModalContentProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-content")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma modal card element][bd].
+///
+/// Defines the properties of the modal card element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard, ModalCardBody, ModalCardHead};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalCardProperties {
+    /// The list of elements found inside the [modal card element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal card element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub children: ChildrenRenderer<ModalCardItem>,
+}
+
+/// Yew implementation of the [Bulma modal card element][bd].
+///
+/// Yew implementation of the modal card element, based on the specification
+/// found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{
+///     Modal, ModalCard, ModalCardBody, ModalCardFoot, ModalCardHead,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>{"Modal actions"}</ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(ModalCard)]
+pub fn modal_card(props: &ModalCardProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-card")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma modal card element][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma modal card element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{
+///     Modal, ModalCard, ModalCardBody, ModalCardFoot, ModalCardHead,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>{"Modal actions"}</ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[derive(Clone, PartialEq)]
+pub enum ModalCardItem {
+    ModalCardHead(VChild<ModalCardHead>),
+    ModalCardBody(VChild<ModalCardBody>),
+    ModalCardFoot(VChild<ModalCardFoot>),
+}
+
+impl From<VChild<ModalCardHead>> for ModalCardItem {
+    fn from(value: VChild<ModalCardHead>) -> Self {
+        ModalCardItem::ModalCardHead(value)
+    }
+}
+
+impl From<VChild<ModalCardBody>> for ModalCardItem {
+    fn from(value: VChild<ModalCardBody>) -> Self {
+        ModalCardItem::ModalCardBody(value)
+    }
+}
+
+impl From<VChild<ModalCardFoot>> for ModalCardItem {
+    fn from(value: VChild<ModalCardFoot>) -> Self {
+        ModalCardItem::ModalCardFoot(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for ModalCardItem {
+    fn into(self) -> Html {
+        match self {
+            ModalCardItem::ModalCardHead(mch) => mch.into(),
+            ModalCardItem::ModalCardBody(mcb) => mcb.into(),
+            ModalCardItem::ModalCardFoot(mcf) => mcf.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma modal card head element][bd].
+///
+/// Defines the properties of the modal card head element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard, ModalCardBody, ModalCardHead};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalCardHeadProperties {
+    /// The title found inside the [modal card head element][bd].
+    ///
+    /// Defines the elements that will be found inside the title of the
+    /// [Bulma modal card head element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal card head element][bd].
+///
+/// Yew implementation of the modal card head element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+/// When placed inside a [`Modal`] which has a close button, it renders a
+/// [`Delete`] button which closes the modal.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard, ModalCardBody, ModalCardHead};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardHead>{"Modal title"}</ModalCardHead>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(ModalCardHead)]
+pub fn modal_card_head(props: &ModalCardHeadProperties) -> Html {
+    let context = use_context::<ModalContext>();
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-card-head")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <header id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <p class="modal-card-title">{ for props.children.iter() }</p>
+            if let Some(context) = context.filter(|context| context.close_button) {
+                <Delete onclick={context.close.reform(|_: MouseEvent| ())} />
+            }
+        </header>
+    }
+}
+
+/// Defines the properties of the [Bulma modal card body element][bd].
+///
+/// Defines the properties of the modal card body element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard, ModalCardBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalCardBodyProperties {
+    /// The list of elements found inside the [modal card body element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal card body element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal card body element][bd].
+///
+/// Yew implementation of the modal card body element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard, ModalCardBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(ModalCardBody)]
+pub fn modal_card_body(props: &ModalCardBodyProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-card-body")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <section id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </section>
+    }
+}
+
+/// Defines the properties of the [Bulma modal card foot element][bd].
+///
+/// Defines the properties of the modal card foot element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::modal::{Modal, ModalCard, ModalCardBody, ModalCardFoot},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>
+///                     <Button>{"Save changes"}</Button>
+///                 </ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalCardFootProperties {
+    /// The list of elements found inside the [modal card foot element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal card foot element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal card foot element][bd].
+///
+/// Yew implementation of the modal card foot element, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::modal::{Modal, ModalCard, ModalCardBody, ModalCardFoot},
+///     elements::button::Button,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal open=true>
+///             <ModalCard>
+///                 <ModalCardBody>{"Modal content"}</ModalCardBody>
+///                 <ModalCardFoot>
+///                     <Button>{"Save changes"}</Button>
+///                 </ModalCardFoot>
+///             </ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(ModalCardFoot)]
+pub fn modal_card_foot(props: &ModalCardFootProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-card-foot")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <footer id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </footer>
+    }
+}
@@ -0,0 +1,1574 @@
+use std::fmt::Display;
+
+use yew::{
+    function_component, html, html::ChildrenRenderer, use_context, use_state, virtual_dom::VChild,
+    AttrValue, Callback, Children, ContextProvider, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX},
+};
+
+/// Defines the possible positions of a fixed [Bulma navbar element][bd].
+///
+/// Defines the possible positions to which a [Bulma navbar element][bd] can
+/// be fixed. Keep in mind that Bulma also expects the `has-navbar-fixed-top`
+/// or `has-navbar-fixed-bottom` class on the `<html>` or `<body>` element,
+/// which is outside of the control of the navbar.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Fixed, Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar fixed={Fixed::Top}>
+///             <NavbarBrand>
+///                 <NavbarItem>{"Brand"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#fixed-navbar
+#[derive(PartialEq)]
+pub enum Fixed {
+    Top,
+    Bottom,
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fixed = match self {
+            Fixed::Top => "fixed-top",
+            Fixed::Bottom => "fixed-bottom",
+        };
+
+        write!(f, "{fixed}")
+    }
+}
+
+/// Shares the burger state between a [`Navbar`] and its children.
+///
+/// Provided by the [`Navbar`] so that the [`NavbarBrand`] can render the
+/// burger button and the [`NavbarMenu`] can be shown or hidden accordingly.
+#[derive(Clone, PartialEq)]
+struct NavbarContext {
+    active: bool,
+    toggle: Callback<()>,
+}
+
+/// Defines the properties of the [Bulma navbar component][bd].
+///
+/// Defines the properties of the navbar component, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem>{"Brand"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarProperties {
+    /// Sets the color of the [Bulma navbar component][bd].
+    ///
+    /// Sets the color of the [Bulma navbar component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::navbar::{Navbar, NavbarBrand, NavbarItem},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar color={Color::Primary}>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the position to which the [Bulma navbar component][bd] is fixed.
+    ///
+    /// Sets the position to which the [Bulma navbar component][bd], which will
+    /// receive these properties, is fixed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Fixed, Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar fixed={Fixed::Bottom}>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#fixed-navbar
+    #[prop_or_default]
+    pub fixed: Option<Fixed>,
+    /// Whether or not the [navbar component][bd] should be transparent.
+    ///
+    /// Whether or not the [Bulma navbar component][bd], which will receive
+    /// these properties, should remove any hover or active background from
+    /// its items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar transparent=true>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#transparent-navbar
+    #[prop_or_default]
+    pub transparent: bool,
+    /// Whether or not the [navbar component][bd] should be spaced.
+    ///
+    /// Whether or not the [Bulma navbar component][bd], which will receive
+    /// these properties, should have more horizontal and vertical padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar spaced=true>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub spaced: bool,
+    /// Whether or not the [navbar component][bd] should have a shadow.
+    ///
+    /// Whether or not the [Bulma navbar component][bd], which will receive
+    /// these properties, should have a small shadow around it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar shadow=true>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub shadow: bool,
+    /// Whether or not the burger and menu of the [navbar component][bd] are active.
+    ///
+    /// Controls whether the burger and menu of the [Bulma navbar component][bd],
+    /// which will receive these properties, are active (opened on touch
+    /// devices). When left unset, the navbar manages this state itself and
+    /// toggles it whenever the burger is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| false);
+    ///     let onburgertoggle = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |value| active.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Navbar active={*active} {onburgertoggle}>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+    #[prop_or_default]
+    pub active: Option<bool>,
+    /// Sets the callback to be used when the burger of the [navbar][bd] is toggled.
+    ///
+    /// Sets the callback to be used when the burger of the
+    /// [Bulma navbar component][bd], which will receive these properties, is
+    /// clicked. The callback receives the new active state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onburgertoggle = Callback::from(|active: bool| {
+    ///         // Do something with the new state.
+    ///     });
+    ///
+    ///     html! {
+    ///         <Navbar {onburgertoggle}>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+    #[prop_or_default]
+    pub onburgertoggle: Option<Callback<bool>>,
+    /// The list of elements found inside the [navbar component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/
+    pub children: ChildrenRenderer<NavbarElement>,
+}
+
+/// Yew implementation of the [Bulma navbar component][bd].
+///
+/// Yew implementation of the navbar component, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarEnd, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+///             </NavbarBrand>
+///
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///                 <NavbarEnd>
+///                     <NavbarItem>{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+#[function_component(Navbar)]
+pub fn navbar(props: &NavbarProperties) -> Html {
+    let active_state = use_state(|| false);
+    let active = props.active.unwrap_or(*active_state);
+    let toggle = {
+        let controlled = props.active.is_some();
+        let onburgertoggle = props.onburgertoggle.clone();
+        Callback::from(move |_| {
+            if !controlled {
+                active_state.set(!active);
+            }
+            if let Some(onburgertoggle) = &onburgertoggle {
+                onburgertoggle.emit(!active);
+            }
+        })
+    };
+    let context = NavbarContext { active, toggle };
+
+    let fixed = props
+        .fixed
+        .as_ref()
+        .map(|fixed| format!("{IS_PREFIX}-{fixed}"))
+        .unwrap_or("".to_owned());
+    let transparent = if props.transparent {
+        "is-transparent"
+    } else {
+        ""
+    };
+    let spaced = if props.spaced { "is-spaced" } else { "" };
+    let shadow = if props.shadow { "has-shadow" } else { "" };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar")
+        .with_color(props.color)
+        .with_custom_class(&fixed)
+        .with_custom_class(transparent)
+        .with_custom_class(spaced)
+        .with_custom_class(shadow)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<NavbarContext> {context}>
+            <nav id={&props.id} {class} role="navigation" aria-label="main navigation"
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </nav>
+        </ContextProvider<NavbarContext>>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma navbar component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma navbar component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem>{"Brand"}</NavbarItem>
+///             </NavbarBrand>
+///
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+#[derive(Clone, PartialEq)]
+pub enum NavbarElement {
+    NavbarBrand(VChild<NavbarBrand>),
+    NavbarMenu(VChild<NavbarMenu>),
+}
+
+impl From<VChild<NavbarBrand>> for NavbarElement {
+    fn from(value: VChild<NavbarBrand>) -> Self {
+        NavbarElement::NavbarBrand(value)
+    }
+}
+
+impl From<VChild<NavbarMenu>> for NavbarElement {
+    fn from(value: VChild<NavbarMenu>) -> Self {
+        NavbarElement::NavbarMenu(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for NavbarElement {
+    fn into(self) -> Html {
+        match self {
+            NavbarElement::NavbarBrand(nb) => nb.into(),
+            NavbarElement::NavbarMenu(nm) => nm.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma navbar brand element][bd].
+///
+/// Defines the properties of the navbar brand element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem>{"Brand"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarBrandProperties {
+    /// Whether or not the [navbar brand element][bd] should contain a burger.
+    ///
+    /// Whether or not the [Bulma navbar brand element][bd], which will
+    /// receive these properties, should render the burger used to toggle the
+    /// navbar menu on touch devices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarBrand burger=false>
+    ///                 <NavbarItem>{"Brand"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+    #[prop_or(true)]
+    pub burger: bool,
+    /// The list of elements found inside the [navbar brand element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar brand element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar brand element][bd].
+///
+/// Yew implementation of the navbar brand element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+/// When placed inside a [`Navbar`], it also renders the burger which toggles
+/// the [`NavbarMenu`] on touch devices.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem>{"Brand"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+#[function_component(NavbarBrand)]
+pub fn navbar_brand(props: &NavbarBrandProperties) -> Html {
+    let context = use_context::<NavbarContext>();
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-brand")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+            if let Some(context) = context.filter(|_| props.burger) {
+                <a role="button" aria-label="menu" aria-expanded={context.active.to_string()}
+                    class={ClassBuilder::default()
+                        .with_custom_class("navbar-burger")
+                        .with_custom_class(if context.active { "is-active" } else { "" })
+                        .build()}
+                    onclick={context.toggle.reform(|_: MouseEvent| ())}>
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                </a>
+            }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar menu element][bd].
+///
+/// Defines the properties of the navbar menu element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarMenuProperties {
+    /// The list of elements found inside the [navbar menu element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar menu element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+    pub children: ChildrenRenderer<NavbarMenuElement>,
+}
+
+/// Yew implementation of the [Bulma navbar menu element][bd].
+///
+/// Yew implementation of the navbar menu element, based on the specification
+/// found in the [Bulma navbar component documentation][bd]. When placed
+/// inside a [`Navbar`], it is shown on touch devices whenever the burger is
+/// active.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+#[function_component(NavbarMenu)]
+pub fn navbar_menu(props: &NavbarMenuProperties) -> Html {
+    let active = use_context::<NavbarContext>()
+        .map(|context| context.active)
+        .unwrap_or(false);
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-menu")
+        .with_custom_class(if active { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma navbar menu element][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma navbar menu element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarEnd, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///                 <NavbarEnd>
+///                     <NavbarItem>{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[derive(Clone, PartialEq)]
+pub enum NavbarMenuElement {
+    NavbarStart(VChild<NavbarStart>),
+    NavbarEnd(VChild<NavbarEnd>),
+}
+
+impl From<VChild<NavbarStart>> for NavbarMenuElement {
+    fn from(value: VChild<NavbarStart>) -> Self {
+        NavbarMenuElement::NavbarStart(value)
+    }
+}
+
+impl From<VChild<NavbarEnd>> for NavbarMenuElement {
+    fn from(value: VChild<NavbarEnd>) -> Self {
+        NavbarMenuElement::NavbarEnd(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for NavbarMenuElement {
+    fn into(self) -> Html {
+        match self {
+            NavbarMenuElement::NavbarStart(ns) => ns.into(),
+            NavbarMenuElement::NavbarEnd(ne) => ne.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma navbar start element][bd].
+///
+/// Defines the properties of the navbar start element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarStartProperties {
+    /// The list of elements found inside the [navbar start element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar start element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar start element][bd].
+///
+/// Yew implementation of the navbar start element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem>{"Home"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[function_component(NavbarStart)]
+pub fn navbar_start(props: &NavbarStartProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-start")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar end element][bd].
+///
+/// Defines the properties of the navbar end element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarEnd, NavbarItem, NavbarMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarEnd>
+///                     <NavbarItem>{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarEndProperties {
+    /// The list of elements found inside the [navbar end element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar end element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar end element][bd].
+///
+/// Yew implementation of the navbar end element, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarEnd, NavbarItem, NavbarMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarEnd>
+///                     <NavbarItem>{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[function_component(NavbarEnd)]
+pub fn navbar_end(props: &NavbarEndProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-end")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar item element][bd].
+///
+/// Defines the properties of the navbar item element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarItemProperties {
+    /// Sets the link of the [Bulma navbar item element][bd].
+    ///
+    /// Sets the link of the [Bulma navbar item element][bd] which will receive
+    /// these properties. When set, the item is rendered as an `<a>` element,
+    /// otherwise as a `<div>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Whether or not the [navbar item element][bd] is active.
+    ///
+    /// Whether or not the [Bulma navbar item element][bd], which will receive
+    /// these properties, is the currently active one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarItem active=true>{"Home"}</NavbarItem>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub active: bool,
+    /// Whether or not the [navbar item element][bd] should be expanded.
+    ///
+    /// Whether or not the [Bulma navbar item element][bd], which will receive
+    /// these properties, should take all the available horizontal space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarItem expanded=true>{"Home"}</NavbarItem>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub expanded: bool,
+    /// Whether or not the [navbar item element][bd] should look like a tab.
+    ///
+    /// Whether or not the [Bulma navbar item element][bd], which will receive
+    /// these properties, should have a bottom border on hover and when active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarItem tab=true active=true>{"Home"}</NavbarItem>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub tab: bool,
+    /// The list of elements found inside the [navbar item element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar item element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar item element][bd].
+///
+/// Yew implementation of the navbar item element, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+#[function_component(NavbarItem)]
+pub fn navbar_item(props: &NavbarItemProperties) -> Html {
+    let active = if props.active { "is-active" } else { "" };
+    let expanded = if props.expanded { "is-expanded" } else { "" };
+    let tab = if props.tab { "is-tab" } else { "" };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-item")
+        .with_custom_class(active)
+        .with_custom_class(expanded)
+        .with_custom_class(tab)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <@{(if props.href.is_some() { "a" } else { "div" }).to_string()} id={&props.id} {class} href={&props.href}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </@>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar dropdown element][bd].
+///
+/// Defines the properties of the navbar dropdown element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarDropdown label={html! {"More"}}>
+///                         <NavbarItem>{"About"}</NavbarItem>
+///                     </NavbarDropdown>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarDropdownProperties {
+    /// Sets the content of the link of the [Bulma navbar dropdown element][bd].
+    ///
+    /// Sets the content of the `navbar-link` element which opens the
+    /// [Bulma navbar dropdown element][bd] receiving these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarDropdown label={html! {"More"}}>
+    ///                         <NavbarItem>{"About"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    pub label: Html,
+    /// Whether or not the [navbar dropdown element][bd] opens on hover.
+    ///
+    /// Whether or not the [Bulma navbar dropdown element][bd], which will
+    /// receive these properties, should open when hovered. Otherwise, it is
+    /// opened and closed by clicking its link.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarDropdown hoverable=true label={html! {"More"}}>
+    ///                         <NavbarItem>{"About"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the [navbar dropdown element][bd] is aligned to the right.
+    ///
+    /// Whether or not the menu of the [Bulma navbar dropdown element][bd],
+    /// which will receive these properties, should be aligned to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Navbar, NavbarDropdown, NavbarEnd, NavbarItem, NavbarMenu,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarEnd>
+    ///                     <NavbarDropdown right=true label={html! {"Account"}}>
+    ///                         <NavbarItem>{"Settings"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarEnd>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub right: bool,
+    /// Whether or not the [navbar dropdown element][bd] should open upwards.
+    ///
+    /// Whether or not the [Bulma navbar dropdown element][bd], which will
+    /// receive these properties, should open above its link, which is useful
+    /// for navbars fixed to the bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Fixed, Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar fixed={Fixed::Bottom}>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarDropdown up=true label={html! {"More"}}>
+    ///                         <NavbarItem>{"About"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub up: bool,
+    /// Whether or not the [navbar dropdown element][bd] should be boxed.
+    ///
+    /// Whether or not the menu of the [Bulma navbar dropdown element][bd],
+    /// which will receive these properties, should look like a box, which is
+    /// useful inside transparent navbars.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar transparent=true>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarDropdown boxed=true label={html! {"More"}}>
+    ///                         <NavbarItem>{"About"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#transparent-navbar
+    #[prop_or_default]
+    pub boxed: bool,
+    /// Whether or not the link of the [navbar dropdown element][bd] has an arrow.
+    ///
+    /// Whether or not the link of the [Bulma navbar dropdown element][bd],
+    /// which will receive these properties, should hide its arrow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{
+    ///     Navbar, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarMenu>
+    ///                 <NavbarStart>
+    ///                     <NavbarDropdown arrowless=true label={html! {"More"}}>
+    ///                         <NavbarItem>{"About"}</NavbarItem>
+    ///                     </NavbarDropdown>
+    ///                 </NavbarStart>
+    ///             </NavbarMenu>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub arrowless: bool,
+    /// The list of elements found inside the [navbar dropdown element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar dropdown element][bd] which will receive these
+    /// properties, such as [`NavbarItem`] and [`NavbarDivider`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar dropdown element][bd].
+///
+/// Yew implementation of the navbar dropdown element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+/// Unless it is hoverable, the dropdown keeps track of whether it is opened
+/// and toggles it whenever its link is clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarDivider, NavbarDropdown, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarDropdown label={html! {"More"}}>
+///                         <NavbarItem>{"About"}</NavbarItem>
+///                         <NavbarDivider />
+///                         <NavbarItem>{"Report an issue"}</NavbarItem>
+///                     </NavbarDropdown>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[function_component(NavbarDropdown)]
+pub fn navbar_dropdown(props: &NavbarDropdownProperties) -> Html {
+    let active = use_state(|| false);
+    let onclick = {
+        let active = active.clone();
+        let hoverable = props.hoverable;
+        Callback::from(move |_: MouseEvent| {
+            if !hoverable {
+                active.set(!*active);
+            }
+        })
+    };
+
+    let hoverable = if props.hoverable { "is-hoverable" } else { "" };
+    let active = if *active { "is-active" } else { "" };
+    let up = if props.up { "has-dropdown-up" } else { "" };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-item has-dropdown")
+        .with_custom_class(hoverable)
+        .with_custom_class(active)
+        .with_custom_class(up)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let link_class = ClassBuilder::default()
+        .with_custom_class("navbar-link")
+        .with_custom_class(if props.arrowless { "is-arrowless" } else { "" })
+        .build();
+    let dropdown_class = ClassBuilder::default()
+        .with_custom_class("navbar-dropdown")
+        .with_custom_class(if props.right { "is-right" } else { "" })
+        .with_custom_class(if props.boxed { "is-boxed" } else { "" })
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a class={link_class} {onclick}>{ props.label.clone() }</a>
+
+            <div class={dropdown_class}>
+                { for props.children.iter() }
+            </div>
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar divider element][bd].
+///
+/// Defines the properties of the navbar divider element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label={html! {"More"}}>
+///             <NavbarItem>{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem>{"Report an issue"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarDividerProperties {}
+
+/// Yew implementation of the [Bulma navbar divider element][bd].
+///
+/// Yew implementation of the navbar divider element, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label={html! {"More"}}>
+///             <NavbarItem>{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem>{"Report an issue"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[function_component(NavbarDivider)]
+pub fn navbar_divider(props: &NavbarDividerProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-divider")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <hr id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    }
+}
@@ -0,0 +1,527 @@
+use yew::{function_component, html, AttrValue, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::Align,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the items found inside the list of a [Bulma pagination component][bd].
+///
+/// Defines the items of a pagination list, as computed by [`page_range`]:
+/// either a link to a page, identified by its number starting at `1`, or an
+/// ellipsis standing for the pages which are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::components::pagination::{page_range, PageItem};
+///
+/// let items = page_range(5, 10, 1, 1);
+///
+/// assert_eq!(items[0], PageItem::Page(1));
+/// assert_eq!(items[1], PageItem::Ellipsis);
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Computes the items of the list of a [Bulma pagination component][bd].
+///
+/// Computes the pages to be shown for the given `current` page, out of
+/// `total_pages`, with pages being numbered starting from `1`. The first and
+/// last `boundaries` pages are always shown, as are the `siblings` pages on
+/// each side of the current page. The other pages are replaced by an
+/// [`PageItem::Ellipsis`], unless only a single page would be skipped, in
+/// which case that page is shown instead. A `current` page out of bounds is
+/// clamped to the first or last page.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::components::pagination::{page_range, PageItem};
+///
+/// assert_eq!(
+///     page_range(46, 86, 1, 1),
+///     vec![
+///         PageItem::Page(1),
+///         PageItem::Ellipsis,
+///         PageItem::Page(45),
+///         PageItem::Page(46),
+///         PageItem::Page(47),
+///         PageItem::Ellipsis,
+///         PageItem::Page(86),
+///     ]
+/// );
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+pub fn page_range(
+    current: usize,
+    total_pages: usize,
+    siblings: usize,
+    boundaries: usize,
+) -> Vec<PageItem> {
+    if total_pages == 0 {
+        return vec![];
+    }
+
+    let current = current.clamp(1, total_pages);
+    let is_shown = |page: usize| {
+        page <= boundaries
+            || page > total_pages.saturating_sub(boundaries)
+            || (current.saturating_sub(siblings) <= page && page <= current + siblings)
+    };
+
+    let mut items = vec![];
+    let mut page = 1;
+    while page <= total_pages {
+        if is_shown(page) {
+            items.push(PageItem::Page(page));
+            page += 1;
+            continue;
+        }
+
+        let next_shown = (page..=total_pages)
+            .find(|&page| is_shown(page))
+            .unwrap_or(total_pages + 1);
+        if next_shown - page == 1 {
+            items.push(PageItem::Page(page));
+        } else {
+            items.push(PageItem::Ellipsis);
+        }
+        page = next_shown;
+    }
+
+    items
+}
+
+/// Defines the properties of the [Bulma pagination component][bd].
+///
+/// Defines the properties of the pagination component, based on the
+/// specification found in the [Bulma pagination component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Pagination current={46} total_pages={86} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PaginationProperties {
+    /// Sets the current page of the [Bulma pagination component][bd].
+    ///
+    /// Sets the current page, starting from `1`, of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    pub current: usize,
+    /// Sets the number of pages of the [Bulma pagination component][bd].
+    ///
+    /// Sets the total number of pages of the [Bulma pagination component][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    pub total_pages: usize,
+    /// Sets the number of pages shown around the current one.
+    ///
+    /// Sets the number of pages shown on each side of the current page of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    /// Defaults to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={10} total_pages={20} siblings={2} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(1)]
+    pub siblings: usize,
+    /// Sets the number of pages always shown at the start and the end.
+    ///
+    /// Sets the number of pages always shown at the start and at the end of
+    /// the [Bulma pagination component][bd] which will receive these
+    /// properties. Defaults to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={10} total_pages={20} boundaries={2} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(1)]
+    pub boundaries: usize,
+    /// Sets the callback to be used when a page of the [pagination][bd] is clicked.
+    ///
+    /// Sets the callback to be used when a page, or the previous or next
+    /// buttons, of the [Bulma pagination component][bd], which will receive
+    /// these properties, are clicked. The callback receives the number of the
+    /// page to go to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let current = use_state(|| 1);
+    ///     let onpagechange = {
+    ///         let current = current.clone();
+    ///         Callback::from(move |page: usize| current.set(page))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Pagination current={*current} total_pages={10} {onpagechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or_default]
+    pub onpagechange: Option<Callback<usize>>,
+    /// Sets the size of the [Bulma pagination component][bd].
+    ///
+    /// Sets the size of the [Bulma pagination component][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::pagination::Pagination,
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} size={Size::Small} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [pagination component][bd] is rounded.
+    ///
+    /// Whether or not the [Bulma pagination component][bd], which will receive
+    /// these properties, should have rounded items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} rounded=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Sets the alignment of the [Bulma pagination component][bd].
+    ///
+    /// Sets the alignment of the list of pages of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::pagination::Pagination,
+    ///     elements::button::Align,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} align={Align::Center} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#alignment
+    #[prop_or_default]
+    pub align: Option<Align>,
+    /// Sets the label of the previous button of the [pagination][bd].
+    ///
+    /// Sets the label of the previous button of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    /// Defaults to `Previous`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} previous_label="Back" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(AttrValue::from("Previous"))]
+    pub previous_label: AttrValue,
+    /// Sets the label of the next button of the [pagination][bd].
+    ///
+    /// Sets the label of the next button of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    /// Defaults to `Next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination current={3} total_pages={10} next_label="Forward" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(AttrValue::from("Next"))]
+    pub next_label: AttrValue,
+}
+
+/// Yew implementation of the [Bulma pagination component][bd].
+///
+/// Yew implementation of the pagination component, based on the
+/// specification found in the [Bulma pagination component documentation][bd].
+/// The list of pages, including the ellipses, is computed using
+/// [`page_range`], while the previous and next buttons are disabled on the
+/// first and last pages respectively.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let current = use_state(|| 1);
+///     let onpagechange = {
+///         let current = current.clone();
+///         Callback::from(move |page: usize| current.set(page))
+///     };
+///
+///     html! {
+///         <Pagination current={*current} total_pages={86} {onpagechange} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProperties) -> Html {
+    let go_to = |page: usize| {
+        let onpagechange = props.onpagechange.clone();
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            if let Some(onpagechange) = &onpagechange {
+                onpagechange.emit(page);
+            }
+        })
+    };
+    let has_previous = props.current > 1;
+    let has_next = props.current < props.total_pages;
+    let onprevious = has_previous.then(|| go_to(props.current - 1));
+    let onnext = has_next.then(|| go_to(props.current + 1));
+
+    let items = page_range(
+        props.current,
+        props.total_pages,
+        props.siblings,
+        props.boundaries,
+    )
+    .into_iter()
+    .map(|item| match item {
+        PageItem::Page(page) => {
+            let is_current = page == props.current;
+            let class = ClassBuilder::default()
+                .with_custom_class("pagination-link")
+                .with_custom_class(if is_current { "is-current" } else { "" })
+                .build();
+            html! {
+                <li>
+                    <a {class}
+                        aria-label={format!("Goto page {page}")}
+                        aria-current={is_current.then(|| "page")}
+                        onclick={go_to(page)}>
+                        { page }
+                    </a>
+                </li>
+            }
+        }
+        PageItem::Ellipsis => html! {
+            <li><span class="pagination-ellipsis">{"\u{2026}"}</span></li>
+        },
+    });
+
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("pagination")
+        .with_custom_class(&size)
+        .with_custom_class(if props.rounded { "is-rounded" } else { "" })
+        .with_custom_class(
+            &props
+                .align
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <nav id={&props.id} {class} role="navigation" aria-label="pagination"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a class="pagination-previous" disabled={!has_previous} onclick={onprevious}>
+                { &props.previous_label }
+            </a>
+            <a class="pagination-next" disabled={!has_next} onclick={onnext}>
+                { &props.next_label }
+            </a>
+            <ul class="pagination-list">
+                { for items }
+            </ul>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    use PageItem::{Ellipsis, Page};
+
+    #[test_case(1, 0, 1, 1, vec![] ; "no pages result in an empty range")]
+    #[test_case(1, 1, 1, 1, vec![Page(1)] ; "a single page is shown")]
+    #[test_case(3, 5, 1, 1, vec![Page(1), Page(2), Page(3), Page(4), Page(5)] ; "few pages are all shown")]
+    #[test_case(46, 86, 1, 1, vec![Page(1), Ellipsis, Page(45), Page(46), Page(47), Ellipsis, Page(86)] ; "middle page has ellipses on both sides")]
+    #[test_case(1, 10, 1, 1, vec![Page(1), Page(2), Ellipsis, Page(10)] ; "first page has an ellipsis at the end")]
+    #[test_case(10, 10, 1, 1, vec![Page(1), Ellipsis, Page(9), Page(10)] ; "last page has an ellipsis at the start")]
+    #[test_case(3, 10, 1, 1, vec![Page(1), Page(2), Page(3), Page(4), Ellipsis, Page(10)] ; "single skipped page is shown instead of an ellipsis")]
+    #[test_case(5, 10, 2, 1, vec![Page(1), Page(2), Page(3), Page(4), Page(5), Page(6), Page(7), Ellipsis, Page(10)] ; "siblings widen the pages around the current one")]
+    #[test_case(10, 20, 1, 2, vec![Page(1), Page(2), Ellipsis, Page(9), Page(10), Page(11), Ellipsis, Page(19), Page(20)] ; "boundaries widen the pages at the edges")]
+    #[test_case(5, 10, 0, 0, vec![Ellipsis, Page(5), Ellipsis] ; "no siblings nor boundaries only show the current page")]
+    #[test_case(0, 10, 1, 1, vec![Page(1), Page(2), Ellipsis, Page(10)] ; "current before the first page is clamped")]
+    #[test_case(20, 10, 1, 1, vec![Page(1), Ellipsis, Page(9), Page(10)] ; "current after the last page is clamped")]
+    fn page_range_computes_pages_and_ellipses(
+        current: usize,
+        total_pages: usize,
+        siblings: usize,
+        boundaries: usize,
+        expected_items: Vec<PageItem>,
+    ) {
+        let items = page_range(current, total_pages, siblings, boundaries);
+
+        assert_eq!(items, expected_items);
+    }
+}
@@ -0,0 +1,1216 @@
+use web_sys::HtmlInputElement;
+use yew::{
+    function_component, html,
+    html::{ChildrenRenderer, TargetCast},
+    use_context, use_state,
+    virtual_dom::VChild,
+    AttrValue, Callback, Children, ChildrenWithProps, ContextProvider, Event, Html, InputEvent,
+    MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{helpers::color::Color, utils::class::ClassBuilder};
+
+/// Shares the search query between a [`Panel`] and its [`PanelSearch`].
+#[derive(Clone, PartialEq)]
+struct PanelContext {
+    query: AttrValue,
+    search: Callback<String>,
+}
+
+/// Shares the selected tab and the change callback between [`PanelTabs`] and
+/// the [`PanelTab`] elements found inside it.
+#[derive(Clone, PartialEq)]
+struct PanelTabsContext<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    selected: K,
+    change: Callback<K>,
+}
+
+/// Defines the properties of the [Bulma panel component][bd].
+///
+/// Defines the properties of the panel component, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelBlock active=true>{"bulma"}</PanelBlock>
+///             <PanelBlock>{"marksheet"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelProperties {
+    /// Sets the color of the [Bulma panel component][bd].
+    ///
+    /// Sets the color of the [Bulma panel component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::panel::{Panel, PanelBlock, PanelHeading},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel color={Color::Primary}>
+    ///             <PanelHeading>{"Repositories"}</PanelHeading>
+    ///             <PanelBlock>{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the predicate used to filter the blocks of the [panel component][bd].
+    ///
+    /// Sets the predicate used to filter the blocks of the
+    /// [Bulma panel component][bd], which will receive these properties, using
+    /// the query typed in its [`PanelSearch`]. The predicate receives the
+    /// query and the `text` of a [`PanelBlock`], and returns whether or not
+    /// the block should be shown. Blocks without a `text` are always shown,
+    /// as are all the blocks while the query is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelSearch};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filter = Callback::from(|(query, text): (String, AttrValue)| {
+    ///         text.to_lowercase().contains(&query.to_lowercase())
+    ///     });
+    ///
+    ///     html! {
+    ///         <Panel {filter}>
+    ///             <PanelSearch />
+    ///             <PanelBlock text="bulma">{"bulma"}</PanelBlock>
+    ///             <PanelBlock text="marksheet">{"marksheet"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub filter: Option<Callback<(String, AttrValue), bool>>,
+    /// The list of elements found inside the [panel component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: ChildrenRenderer<PanelItem>,
+}
+
+/// Yew implementation of the [Bulma panel component][bd].
+///
+/// Yew implementation of the panel component, based on the specification
+/// found in the [Bulma panel component documentation][bd]. The panel keeps
+/// track of the query typed in its [`PanelSearch`], if any, and hides the
+/// [`PanelBlock`] children rejected by its `filter` predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::panel::{Panel, PanelBlock, PanelHeading, PanelSearch, PanelTab, PanelTabs},
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filter = Callback::from(|(query, text): (String, AttrValue)| {
+///         text.to_lowercase().contains(&query.to_lowercase())
+///     });
+///
+///     html! {
+///         <Panel color={Color::Link} {filter}>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelSearch />
+///             <PanelTabs<u32> selected={1}>
+///                 <PanelTab<u32> value={1} label="All" />
+///                 <PanelTab<u32> value={2} label="Public" />
+///             </PanelTabs<u32>>
+///             <PanelBlock text="bulma" active=true>{"bulma"}</PanelBlock>
+///             <PanelBlock text="marksheet">{"marksheet"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(Panel)]
+pub fn panel(props: &PanelProperties) -> Html {
+    let query = use_state(String::new);
+    let context = PanelContext {
+        query: AttrValue::from((*query).clone()),
+        search: {
+            let query = query.clone();
+            Callback::from(move |value: String| query.set(value))
+        },
+    };
+    let is_shown = |item: &PanelItem| match (item, &props.filter) {
+        (PanelItem::PanelBlock(block), Some(filter)) if !query.is_empty() => block
+            .props
+            .text
+            .as_ref()
+            .map(|text| filter.emit(((*query).clone(), text.clone())))
+            .unwrap_or(true),
+        _ => true,
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel")
+        .with_color(props.color)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<PanelContext> {context}>
+            <nav id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter().filter(is_shown) }
+            </nav>
+        </ContextProvider<PanelContext>>
+    }
+}
+
+/// Defines the possible types of children from a [Bulma panel component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma panel component][bd]. Since the [`PanelTabs`] are generic over the
+/// key of their tabs, they are stored already rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelBlock>{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[derive(Clone, PartialEq)]
+pub enum PanelItem {
+    PanelHeading(VChild<PanelHeading>),
+    PanelSearch(VChild<PanelSearch>),
+    PanelTabs(Html),
+    PanelBlock(VChild<PanelBlock>),
+}
+
+impl From<VChild<PanelHeading>> for PanelItem {
+    fn from(value: VChild<PanelHeading>) -> Self {
+        PanelItem::PanelHeading(value)
+    }
+}
+
+impl From<VChild<PanelSearch>> for PanelItem {
+    fn from(value: VChild<PanelSearch>) -> Self {
+        PanelItem::PanelSearch(value)
+    }
+}
+
+impl<K> From<VChild<PanelTabs<K>>> for PanelItem
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<PanelTabs<K>>) -> Self {
+        PanelItem::PanelTabs(value.into())
+    }
+}
+
+impl From<VChild<PanelBlock>> for PanelItem {
+    fn from(value: VChild<PanelBlock>) -> Self {
+        PanelItem::PanelBlock(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for PanelItem {
+    fn into(self) -> Html {
+        match self {
+            PanelItem::PanelHeading(ph) => ph.into(),
+            PanelItem::PanelSearch(ps) => ps.into(),
+            PanelItem::PanelTabs(pt) => pt,
+            PanelItem::PanelBlock(pb) => pb.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma panel heading element][bd].
+///
+/// Defines the properties of the panel heading element, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelHeadingProperties {
+    /// The list of elements found inside the [panel heading element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel heading element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel heading element][bd].
+///
+/// Yew implementation of the panel heading element, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelHeading)]
+pub fn panel_heading(props: &PanelHeadingProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-heading")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
+
+/// Defines the properties of the search block of a [Bulma panel component][bd].
+///
+/// Defines the properties of the panel block holding the search input, based
+/// on the specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelSearch />
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelSearchProperties {
+    /// Sets the placeholder of the search block of a [panel component][bd].
+    ///
+    /// Sets the placeholder of the search input found inside the search block
+    /// of the [Bulma panel component][bd] which will receive these
+    /// properties. Defaults to `Search`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelSearch};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelSearch placeholder="Find a repository" />
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or(AttrValue::from("Search"))]
+    pub placeholder: AttrValue,
+}
+
+/// Yew implementation of the search block of a [Bulma panel component][bd].
+///
+/// Yew implementation of the panel block holding the search input, based on
+/// the specification found in the [Bulma panel component documentation][bd].
+/// When placed inside a [`Panel`], the typed query is used to filter the
+/// blocks of the panel.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filter = Callback::from(|(query, text): (String, AttrValue)| {
+///         text.contains(&query)
+///     });
+///
+///     html! {
+///         <Panel {filter}>
+///             <PanelSearch />
+///             <PanelBlock text="bulma">{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelSearch)]
+pub fn panel_search(props: &PanelSearchProperties) -> Html {
+    let context = use_context::<PanelContext>();
+    let value = context.as_ref().map(|context| context.query.clone());
+    let oninput = {
+        let oninput = props.oninput.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(context) = &context {
+                context
+                    .search
+                    .emit(event.target_unchecked_into::<HtmlInputElement>().value());
+            }
+            if let Some(oninput) = &oninput {
+                oninput.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-block")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <p class="control">
+                <input class="input" type="text" placeholder={&props.placeholder} {value} {oninput} />
+            </p>
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma panel tabs element][bd].
+///
+/// Defines the properties of the panel tabs element, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelTabs<u32> selected={1}>
+///                 <PanelTab<u32> value={1} label="All" />
+///                 <PanelTab<u32> value={2} label="Public" />
+///             </PanelTabs<u32>>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelTabsProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the selected tab of the [panel tabs element][bd].
+    ///
+    /// Sets the key of the selected tab of the [Bulma panel tabs element][bd]
+    /// which will receive these properties. The [`PanelTab`] whose value
+    /// matches this key is marked as active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelTabs<&'static str> selected="public">
+    ///                 <PanelTab<&'static str> value="all" label="All" />
+    ///                 <PanelTab<&'static str> value="public" label="Public" />
+    ///             </PanelTabs<&'static str>>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub selected: K,
+    /// Sets the callback to be used when a tab of the [panel tabs][bd] is clicked.
+    ///
+    /// Sets the callback to be used when a tab of the
+    /// [Bulma panel tabs element][bd], which will receive these properties,
+    /// is clicked. The callback receives the value of the clicked tab, which
+    /// should be used to update the `selected` property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let selected = use_state(|| 1);
+    ///     let onchange = {
+    ///         let selected = selected.clone();
+    ///         Callback::from(move |value: u32| selected.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelTabs<u32> selected={*selected} {onchange}>
+    ///                 <PanelTab<u32> value={1} label="All" />
+    ///                 <PanelTab<u32> value={2} label="Public" />
+    ///             </PanelTabs<u32>>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub onchange: Option<Callback<K>>,
+    /// The list of tabs found inside the [panel tabs element][bd].
+    ///
+    /// Defines the tabs that will be found inside the
+    /// [Bulma panel tabs element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: ChildrenWithProps<PanelTab<K>>,
+}
+
+/// Yew implementation of the [Bulma panel tabs element][bd].
+///
+/// Yew implementation of the panel tabs element, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelTabs<u32> selected={1}>
+///                 <PanelTab<u32> value={1} label="All" />
+///                 <PanelTab<u32> value={2} label="Public" />
+///             </PanelTabs<u32>>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelTabs)]
+pub fn panel_tabs<K>(props: &PanelTabsProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let change = {
+        let onchange = props.onchange.clone();
+        Callback::from(move |value: K| {
+            if let Some(onchange) = &onchange {
+                onchange.emit(value);
+            }
+        })
+    };
+    let context = PanelTabsContext {
+        selected: props.selected.clone(),
+        change,
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-tabs")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<PanelTabsContext<K>> {context}>
+            <p id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </p>
+        </ContextProvider<PanelTabsContext<K>>>
+    }
+}
+
+/// Defines the properties of a tab of the [Bulma panel tabs element][bd].
+///
+/// Defines the properties of a tab found inside the panel tabs element, based
+/// on the specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelTabs<u32> selected={1}>
+///                 <PanelTab<u32> value={1} label="All" />
+///             </PanelTabs<u32>>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelTabProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of a tab of the [Bulma panel tabs element][bd].
+    ///
+    /// Sets the key of the tab which will receive these properties. The tab
+    /// is active when this key is equal to the `selected` key of its
+    /// [`PanelTabs`], and it is passed to their `onchange` callback when the
+    /// tab is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelTabs<u32> selected={1}>
+    ///                 <PanelTab<u32> value={1} label="All" />
+    ///             </PanelTabs<u32>>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub value: K,
+    /// Sets the label of a tab of the [Bulma panel tabs element][bd].
+    ///
+    /// Sets the label of the tab which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelTabs<u32> selected={1}>
+    ///                 <PanelTab<u32> value={1} label="All" />
+    ///             </PanelTabs<u32>>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub label: AttrValue,
+}
+
+/// Yew implementation of a tab of the [Bulma panel tabs element][bd].
+///
+/// Yew implementation of a tab found inside the panel tabs element, based on
+/// the specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTab, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelTabs<u32> selected={1}>
+///                 <PanelTab<u32> value={1} label="All" />
+///                 <PanelTab<u32> value={2} label="Public" />
+///             </PanelTabs<u32>>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelTab)]
+pub fn panel_tab<K>(props: &PanelTabProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let context = use_context::<PanelTabsContext<K>>();
+    let active = context
+        .as_ref()
+        .map(|context| context.selected == props.value)
+        .unwrap_or(false);
+    let onclick = {
+        let value = props.value.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(context) = &context {
+                context.change.emit(value.clone());
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class(if active { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <a id={&props.id} {class} {onclick}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { &props.label }
+        </a>
+    }
+}
+
+/// Defines the properties of the [Bulma panel block element][bd].
+///
+/// Defines the properties of the panel block element, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock>{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelBlockProperties {
+    /// Whether or not the [panel block element][bd] is active.
+    ///
+    /// Whether or not the [Bulma panel block element][bd], which will receive
+    /// these properties, is active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock active=true>{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub active: bool,
+    /// Sets the link of the [Bulma panel block element][bd].
+    ///
+    /// Sets the link of the [Bulma panel block element][bd] which will
+    /// receive these properties. Blocks with a link or an `onclick` callback
+    /// are rendered as an `<a>` HTML tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock href="https://bulma.io">{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the text used to filter the [Bulma panel block element][bd].
+    ///
+    /// Sets the text passed, along with the search query, to the `filter`
+    /// predicate of the [`Panel`] holding the [Bulma panel block element][bd]
+    /// which will receive these properties. Blocks without a text are never
+    /// filtered out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelSearch};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filter = Callback::from(|(query, text): (String, AttrValue)| {
+    ///         text.contains(&query)
+    ///     });
+    ///
+    ///     html! {
+    ///         <Panel {filter}>
+    ///             <PanelSearch />
+    ///             <PanelBlock text="bulma">{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub text: Option<AttrValue>,
+    /// Whether or not the [panel block element][bd] holds a checkbox.
+    ///
+    /// Whether or not the [Bulma panel block element][bd], which will receive
+    /// these properties, should be rendered as a label holding a checkbox,
+    /// followed by its children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock checkbox=true>{"Remember me"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub checkbox: bool,
+    /// Whether or not the checkbox of the [panel block element][bd] is checked.
+    ///
+    /// Whether or not the checkbox of the [Bulma panel block element][bd],
+    /// which will receive these properties, is checked. Only used when the
+    /// block is a `checkbox`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock checkbox=true checked=true>{"Remember me"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub checked: bool,
+    /// Sets the callback to be used when the checkbox of the [panel block][bd] changes.
+    ///
+    /// Sets the callback to be used when the checkbox of the
+    /// [Bulma panel block element][bd], which will receive these properties,
+    /// is checked or unchecked. The callback receives whether or not the
+    /// checkbox is now checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let checked = use_state(|| false);
+    ///     let oncheck = {
+    ///         let checked = checked.clone();
+    ///         Callback::from(move |value: bool| checked.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock checkbox=true checked={*checked} {oncheck}>
+    ///                 {"Remember me"}
+    ///             </PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub oncheck: Option<Callback<bool>>,
+    /// The list of elements found inside the [panel block element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel block element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel block element][bd].
+///
+/// Yew implementation of the panel block element, based on the specification
+/// found in the [Bulma panel component documentation][bd]. The block is
+/// rendered as a `<label>` HTML tag in its checkbox variant, as an `<a>` HTML
+/// tag when it has a link or an `onclick` callback, and as a `<div>` HTML tag
+/// otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelIcon};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock active=true href="#">
+///                 <PanelIcon><i class="fas fa-book"></i></PanelIcon>
+///                 {"bulma"}
+///             </PanelBlock>
+///             <PanelBlock checkbox=true>{"Remember me"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelBlock)]
+pub fn panel_block(props: &PanelBlockProperties) -> Html {
+    let tag = if props.checkbox {
+        "label"
+    } else if props.href.is_some() || props.onclick.is_some() {
+        "a"
+    } else {
+        "div"
+    };
+    let onchange = {
+        let oncheck = props.oncheck.clone();
+        Callback::from(move |event: Event| {
+            if let Some(oncheck) = &oncheck {
+                oncheck.emit(event.target_unchecked_into::<HtmlInputElement>().checked());
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-block")
+        .with_custom_class(if props.active { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <@{tag.to_string()} id={&props.id} {class} href={&props.href}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if props.checkbox {
+                <input type="checkbox" checked={props.checked} {onchange} />
+            }
+            { for props.children.iter() }
+        </@>
+    }
+}
+
+/// Defines the properties of the [Bulma panel icon element][bd].
+///
+/// Defines the properties of the panel icon element, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelIcon};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock>
+///                 <PanelIcon><i class="fas fa-book"></i></PanelIcon>
+///                 {"bulma"}
+///             </PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelIconProperties {
+    /// The list of elements found inside the [panel icon element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel icon element][bd] which will receive these properties,
+    /// usually the framework specific HTML of the icon.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel icon element][bd].
+///
+/// Yew implementation of the panel icon element, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelIcon};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock>
+///                 <PanelIcon><i class="fas fa-book"></i></PanelIcon>
+///                 {"bulma"}
+///             </PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelIcon)]
+pub fn panel_icon(props: &PanelIconProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-icon")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <span id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </span>
+    }
+}
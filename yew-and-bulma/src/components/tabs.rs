@@ -0,0 +1,773 @@
+use yew::{
+    function_component, html, html::ChildrenRenderer, use_context, virtual_dom::VChild, AttrValue,
+    Callback, Children, ContextProvider, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{button::Align, icon::Icon},
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Shares the selected tab and the change callback between [`Tabs`] and the
+/// [`Tab`] elements found inside it.
+#[derive(Clone, PartialEq)]
+struct TabsContext<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    selected: K,
+    change: Callback<K>,
+}
+
+/// Defines the possible styles of the [Bulma tabs component][bd].
+///
+/// Defines the possible styles of the tabs found inside a
+/// [Bulma tabs component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Style, Tab, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1} style={Style::Boxed}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/#styles
+#[derive(PartialEq)]
+pub enum Style {
+    Boxed,
+    Toggle,
+    ToggleRounded,
+}
+
+impl From<&Style> for String {
+    fn from(value: &Style) -> Self {
+        match value {
+            Style::Boxed => format!("{IS_PREFIX}-boxed"),
+            Style::Toggle => format!("{IS_PREFIX}-toggle"),
+            Style::ToggleRounded => format!("{IS_PREFIX}-toggle {IS_PREFIX}-toggle-rounded"),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma tabs component][bd].
+///
+/// Defines the properties of the tabs component, based on the specification
+/// found in the [Bulma tabs component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TabsProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the selected tab of the [tabs component][bd].
+    ///
+    /// Sets the key of the selected tab of the [Bulma tabs component][bd]
+    /// which will receive these properties. The [`Tab`] whose value matches
+    /// this key is marked as active, as is the [`TabPanel`] with the same
+    /// value, which is the only panel rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<&'static str> selected="music">
+    ///             <Tab<&'static str> value="pictures" label="Pictures" />
+    ///             <Tab<&'static str> value="music" label="Music" />
+    ///         </Tabs<&'static str>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub selected: K,
+    /// Sets the callback to be used when a tab of the [tabs component][bd] is clicked.
+    ///
+    /// Sets the callback to be used when a tab of the
+    /// [Bulma tabs component][bd], which will receive these properties, is
+    /// clicked. The callback receives the value of the clicked tab, which
+    /// should be used to update the `selected` property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let selected = use_state(|| 1);
+    ///     let onchange = {
+    ///         let selected = selected.clone();
+    ///         Callback::from(move |value: u32| selected.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Tabs<u32> selected={*selected} {onchange}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <Tab<u32> value={2} label="Music" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub onchange: Option<Callback<K>>,
+    /// Sets the style of the [Bulma tabs component][bd].
+    ///
+    /// Sets the style of the [Bulma tabs component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Style, Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1} style={Style::ToggleRounded}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <Tab<u32> value={2} label="Music" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#styles
+    #[prop_or_default]
+    pub style: Option<Style>,
+    /// Sets the alignment of the [Bulma tabs component][bd].
+    ///
+    /// Sets the alignment of the tabs found inside the
+    /// [Bulma tabs component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::tabs::{Tab, Tabs},
+    ///     elements::button::Align,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1} align={Align::Center}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <Tab<u32> value={2} label="Music" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#alignment
+    #[prop_or_default]
+    pub align: Option<Align>,
+    /// Sets the size of the [Bulma tabs component][bd].
+    ///
+    /// Sets the size of the [Bulma tabs component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::tabs::{Tab, Tabs},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1} size={Size::Large}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <Tab<u32> value={2} label="Music" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [tabs component][bd] takes the whole width.
+    ///
+    /// Whether or not the tabs of the [Bulma tabs component][bd], which will
+    /// receive these properties, should take up the whole available width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1} full_width=true>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <Tab<u32> value={2} label="Music" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#fullwidth
+    #[prop_or_default]
+    pub full_width: bool,
+    /// The list of elements found inside the [tabs component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma tabs component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub children: ChildrenRenderer<TabsItem<K>>,
+}
+
+/// Yew implementation of the [Bulma tabs component][bd].
+///
+/// Yew implementation of the tabs component, based on the specification found
+/// in the [Bulma tabs component documentation][bd]. The [`Tab`] children are
+/// rendered inside the tabs list, while only the [`TabPanel`] matching the
+/// `selected` key is rendered, right after the tabs.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let selected = use_state(|| 1);
+///     let onchange = {
+///         let selected = selected.clone();
+///         Callback::from(move |value: u32| selected.set(value))
+///     };
+///
+///     html! {
+///         <Tabs<u32> selected={*selected} {onchange}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///
+///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+///             <TabPanel<u32> value={2}>{"Some music"}</TabPanel<u32>>
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[function_component(Tabs)]
+pub fn tabs<K>(props: &TabsProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let change = {
+        let onchange = props.onchange.clone();
+        Callback::from(move |value: K| {
+            if let Some(onchange) = &onchange {
+                onchange.emit(value);
+            }
+        })
+    };
+    let context = TabsContext {
+        selected: props.selected.clone(),
+        change,
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("tabs")
+        .with_custom_class(
+            &props
+                .style
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(
+            &props
+                .align
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(&size)
+        .with_custom_class(if props.full_width { "is-fullwidth" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let panel = props.children.iter().find(|child| match child {
+        TabsItem::Tab(_) => false,
+        TabsItem::TabPanel(panel) => panel.props.value == props.selected,
+    });
+
+    html! {
+        <ContextProvider<TabsContext<K>> {context}>
+            <div id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                <ul>
+                    { for props.children.iter().filter(|child| child.is_tab()) }
+                </ul>
+            </div>
+            { for panel }
+        </ContextProvider<TabsContext<K>>>
+    }
+}
+
+/// Defines the possible types of children of a [Bulma tabs component][bd].
+///
+/// Defines the possible types of children found inside a
+/// [Bulma tabs component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[derive(Clone, PartialEq)]
+pub enum TabsItem<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    Tab(VChild<Tab<K>>),
+    TabPanel(VChild<TabPanel<K>>),
+}
+
+impl<K> TabsItem<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Returns whether or not the item is a [`Tab`].
+    pub fn is_tab(&self) -> bool {
+        matches!(self, TabsItem::Tab(_))
+    }
+
+    /// Returns whether or not the item is a [`TabPanel`].
+    pub fn is_tab_panel(&self) -> bool {
+        matches!(self, TabsItem::TabPanel(_))
+    }
+}
+
+impl<K> From<VChild<Tab<K>>> for TabsItem<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<Tab<K>>) -> Self {
+        TabsItem::Tab(value)
+    }
+}
+
+impl<K> From<VChild<TabPanel<K>>> for TabsItem<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(value: VChild<TabPanel<K>>) -> Self {
+        TabsItem::TabPanel(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<K> Into<Html> for TabsItem<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn into(self) -> Html {
+        match self {
+            TabsItem::Tab(t) => t.into(),
+            TabsItem::TabPanel(tp) => tp.into(),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma tab element][bd].
+///
+/// Defines the properties of a tab found inside the tabs component, based on
+/// the specification found in the [Bulma tabs component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TabProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the [Bulma tab element][bd].
+    ///
+    /// Sets the key of the [Bulma tab element][bd] which will receive these
+    /// properties. The tab is active when this key is equal to the `selected`
+    /// key of its [`Tabs`], and it is passed to their `onchange` callback when
+    /// the tab is clicked. Since `key` is reserved by Yew, the key is set using
+    /// the `value` property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub value: K,
+    /// Sets the label of the [Bulma tab element][bd].
+    ///
+    /// Sets the label of the [Bulma tab element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub label: AttrValue,
+    /// Sets the icon of the [Bulma tab element][bd].
+    ///
+    /// Sets the icon shown before the label of the [Bulma tab element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::tabs::{Tab, Tabs},
+    ///     elements::icon::Icon,
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html_nested! {
+    ///         <Icon size={Size::Small} icon={html! { <i class="fas fa-image"></i> }} />
+    ///     };
+    ///
+    ///     html! {
+    ///         <Tabs<u32> selected={1}>
+    ///             <Tab<u32> value={1} label="Pictures" {icon} />
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#icons
+    #[prop_or_default]
+    pub icon: Option<VChild<Icon>>,
+}
+
+/// Yew implementation of the [Bulma tab element][bd].
+///
+/// Yew implementation of a tab found inside the tabs component, based on the
+/// specification found in the [Bulma tabs component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[function_component(Tab)]
+pub fn tab<K>(props: &TabProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let context = use_context::<TabsContext<K>>();
+    let active = context
+        .as_ref()
+        .map(|context| context.selected == props.value)
+        .unwrap_or(false);
+    let onclick = {
+        let value = props.value.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(context) = &context {
+                context.change.emit(value.clone());
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class(if active { "is-active" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <li id={&props.id} {class}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a {onclick}>
+                if let Some(icon) = props.icon.clone() {
+                    { icon }
+                }
+                <span>{ &props.label }</span>
+            </a>
+        </li>
+    }
+}
+
+/// Defines the properties of a tab panel.
+///
+/// Defines the properties of a panel holding the content of a tab of the
+/// [Bulma tabs component][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TabPanelProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// Sets the key of the tab panel.
+    ///
+    /// Sets the key of the tab panel which will receive these properties. The
+    /// panel is only rendered when this key is equal to the `selected` key of
+    /// its [`Tabs`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tabs<u32> selected={1}>
+    ///             <Tab<u32> value={1} label="Pictures" />
+    ///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+    ///         </Tabs<u32>>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub value: K,
+    /// The list of elements found inside the tab panel.
+    ///
+    /// Defines the elements that will be found inside the tab panel which will
+    /// receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    pub children: Children,
+}
+
+/// Renders the content of a tab of the [Bulma tabs component][bd].
+///
+/// Renders the content of a tab of the tabs component. When placed inside a
+/// [`Tabs`], it is only rendered when its value matches the selected key.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tab, TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Tabs<u32> selected={1}>
+///             <Tab<u32> value={1} label="Pictures" />
+///             <Tab<u32> value={2} label="Music" />
+///
+///             <TabPanel<u32> value={1}>{"Some pictures"}</TabPanel<u32>>
+///             <TabPanel<u32> value={2}>{"Some music"}</TabPanel<u32>>
+///         </Tabs<u32>>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[function_component(TabPanel)]
+pub fn tab_panel<K>(props: &TabPanelProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,3200 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::Range,
+    rc::Rc,
+};
+
+use yew::{
+    classes, function_component, html, html_nested, use_mut_ref, use_state, use_state_eq,
+    virtual_dom::VChild, AttrValue, Callback, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    components::pagination::Pagination,
+    elements::{
+        icon::Icon,
+        table::{
+            initial_window, Table, TableData, TableFooter, TableGroup, TableHeader, TableHeaderRow,
+            TableItem, TableRow, VirtualScroll,
+        },
+        table_filter::{filtered_positions, Filter, Filters},
+    },
+    form::{
+        general::{Control, Field},
+        input::{Input, InputType},
+        select::Select,
+    },
+    helpers::color::TextColor,
+    layout::level::{Level, LevelItem, LevelLeft, LevelRight},
+    utils::{constants::IS_CLICKABLE, size::Size},
+};
+
+/// Defines the renderer of the footer of a [`Column`], which receives the rows
+/// of the table kept by its filters.
+type FooterRenderer<T> = Rc<dyn Fn(&[&T]) -> Html>;
+/// Defines the comparison of two rows by the sort key of a [`Column`].
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+/// Defines the extraction of the text of the cells of a [`Column`], used when
+/// exporting the table.
+type TextExtractor<T> = Rc<dyn Fn(&T) -> String>;
+
+/// Defines the direction in which a [`DataTable`] is sorted by a column.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::elements::data_table::Direction;
+///
+/// assert_eq!(Direction::Ascending.to_string(), "ascending");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self {
+            Direction::Ascending => "ascending",
+            Direction::Descending => "descending",
+        };
+
+        write!(f, "{direction}")
+    }
+}
+
+/// Defines a column of a [`DataTable`].
+///
+/// Defines how a column of a [`DataTable`] is rendered: the label of its
+/// header, the renderer of each of its cells and, optionally, the aggregate
+/// shown in its footer, such as a total, the key by which its rows can be
+/// sorted, the text of its cells, used when searching and exporting, and the
+/// [`Filter`] of its rows. Columns are identified by their id, which defaults
+/// to their header label.
+///
+/// Two columns are equal if they have the same header and id and share the
+/// same renderers, sort key, text extractor and filter.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::Column;
+///
+/// struct Product {
+///     name: String,
+///     price: u32,
+/// }
+///
+/// let columns = vec![
+///     Column::new("Name", |product: &Product| html! { &product.name }),
+///     Column::new("Price", |product: &Product| html! { product.price })
+///         .with_footer(|products: &[&Product]| {
+///             html! { products.iter().map(|product| product.price).sum::<u32>() }
+///         }),
+/// ];
+/// ```
+pub struct Column<T> {
+    id: AttrValue,
+    header: AttrValue,
+    cell: Rc<dyn Fn(&T) -> Html>,
+    footer: Option<FooterRenderer<T>>,
+    sort: Option<Comparator<T>>,
+    text: Option<TextExtractor<T>>,
+    filter: Option<Filter<T>>,
+}
+
+impl<T> Column<T> {
+    /// Creates a column with the given header label and cell renderer.
+    pub fn new<H, F>(header: H, cell: F) -> Self
+    where
+        H: Into<AttrValue>,
+        F: Fn(&T) -> Html + 'static,
+    {
+        let header = header.into();
+        Self {
+            id: header.clone(),
+            header,
+            cell: Rc::new(cell),
+            footer: None,
+            sort: None,
+            text: None,
+            filter: None,
+        }
+    }
+
+    /// Sets the id of the column, used to refer to it when sorting.
+    pub fn with_id<I: Into<AttrValue>>(mut self, id: I) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Sets the renderer of the footer of the column, which receives the rows
+    /// of the table kept by its filters.
+    pub fn with_footer<F>(mut self, footer: F) -> Self
+    where
+        F: Fn(&[&T]) -> Html + 'static,
+    {
+        self.footer = Some(Rc::new(footer));
+        self
+    }
+
+    /// Makes the column sortable, using the key extracted from each row.
+    pub fn with_sort_key<K, F>(mut self, key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'static,
+    {
+        self.sort = Some(Rc::new(move |a: &T, b: &T| key(a).cmp(&key(b))));
+        self
+    }
+
+    /// Sets the text of the cells of the column, used when searching and
+    /// exporting the table.
+    pub fn with_text<F>(mut self, text: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.text = Some(Rc::new(text));
+        self
+    }
+
+    /// Sets the filter of the rows by the column, shown below its header.
+    pub fn with_filter(mut self, filter: Filter<T>) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Returns the id of the column.
+    pub fn id(&self) -> &AttrValue {
+        &self.id
+    }
+
+    /// Returns the header label of the column.
+    pub fn header(&self) -> &AttrValue {
+        &self.header
+    }
+
+    /// Renders the cell of the column for the given row.
+    pub fn cell(&self, row: &T) -> Html {
+        (self.cell)(row)
+    }
+
+    /// Renders the footer of the column for the given rows, if it has one.
+    pub fn footer(&self, rows: &[&T]) -> Option<Html> {
+        self.footer.as_ref().map(|footer| footer(rows))
+    }
+
+    /// Whether or not the column has a footer.
+    pub fn has_footer(&self) -> bool {
+        self.footer.is_some()
+    }
+
+    /// Compares two rows by the sort key of the column.
+    ///
+    /// Rows are always equal for columns which are not sortable.
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.sort
+            .as_ref()
+            .map(|sort| sort(a, b))
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Whether or not the column is sortable.
+    pub fn is_sortable(&self) -> bool {
+        self.sort.is_some()
+    }
+
+    /// Returns the text of the cell of the column for the given row, if it
+    /// has a text extractor.
+    pub fn text(&self, row: &T) -> Option<String> {
+        self.text.as_ref().map(|text| text(row))
+    }
+
+    /// Whether or not the column has a text extractor.
+    pub fn has_text(&self) -> bool {
+        self.text.is_some()
+    }
+
+    /// Returns the filter of the column, if it has one.
+    pub fn filter(&self) -> Option<&Filter<T>> {
+        self.filter.as_ref()
+    }
+
+    /// Whether or not the column has a filter.
+    pub fn has_filter(&self) -> bool {
+        self.filter.is_some()
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            footer: self.footer.clone(),
+            sort: self.sort.clone(),
+            text: self.text.clone(),
+            filter: self.filter.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && same_renderer(&self.footer, &other.footer)
+            && same_renderer(&self.sort, &other.sort)
+            && same_renderer(&self.text, &other.text)
+            && self.filter == other.filter
+    }
+}
+
+/// Whether or not two optional renderers are both missing or the same.
+fn same_renderer<R: ?Sized>(a: &Option<Rc<R>>, b: &Option<Rc<R>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Returns the sort following a click on the header of the given column.
+///
+/// The direction of the column cycles through ascending, descending and
+/// unsorted. When `multiple` is set, ie when the header is shift-clicked, the
+/// other columns keep their direction and the column is added at the end of
+/// the sort. Otherwise, the table is sorted only by the clicked column.
+fn next_sort(
+    sort: &[(AttrValue, Direction)],
+    column: &AttrValue,
+    multiple: bool,
+) -> Vec<(AttrValue, Direction)> {
+    let next = match sort.iter().find(|(id, _)| id == column) {
+        None => Some(Direction::Ascending),
+        Some((_, Direction::Ascending)) => Some(Direction::Descending),
+        Some((_, Direction::Descending)) => None,
+    };
+
+    if !multiple {
+        return next
+            .map(|direction| vec![(column.clone(), direction)])
+            .unwrap_or_default();
+    }
+
+    let mut sort = sort.to_vec();
+    match (sort.iter().position(|(id, _)| id == column), next) {
+        (Some(position), Some(direction)) => sort[position].1 = direction,
+        (Some(position), None) => {
+            sort.remove(position);
+        }
+        (None, Some(direction)) => sort.push((column.clone(), direction)),
+        (None, None) => {}
+    }
+
+    sort
+}
+
+/// Returns the given positions of the rows, in the given sort order.
+///
+/// The sort is stable, so rows which are equal by all of the sorted columns
+/// keep their given order. Columns which are not found or not sortable are
+/// ignored.
+pub(crate) fn sorted_positions<T>(
+    rows: &[T],
+    columns: &[Column<T>],
+    sort: &[(AttrValue, Direction)],
+    mut positions: Vec<usize>,
+) -> Vec<usize> {
+    let keys: Vec<_> = sort
+        .iter()
+        .filter_map(|(id, direction)| {
+            columns
+                .iter()
+                .find(|column| column.id() == id && column.is_sortable())
+                .map(|column| (column, *direction))
+        })
+        .collect();
+    if keys.is_empty() {
+        return positions;
+    }
+
+    positions.sort_by(|&a, &b| {
+        keys.iter()
+            .map(|(column, direction)| {
+                let ordering = column.compare(&rows[a], &rows[b]);
+                match direction {
+                    Direction::Ascending => ordering,
+                    Direction::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    positions
+}
+
+/// Defines the key identifying a row of a [`DataTable`], ie when selected.
+pub type RowKey = AttrValue;
+
+/// Defines how the [`RowKey`] of each row of a [`DataTable`] is extracted.
+///
+/// Two extractors are equal if they share the same function.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::KeyExtractor;
+///
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let row_key = KeyExtractor::new(|user: &User| AttrValue::from(user.id.to_string()));
+/// ```
+pub struct KeyExtractor<T>(Rc<dyn Fn(&T) -> RowKey>);
+
+impl<T> KeyExtractor<T> {
+    /// Creates an extractor from the given function.
+    pub fn new<F: Fn(&T) -> RowKey + 'static>(key: F) -> Self {
+        Self(Rc::new(key))
+    }
+
+    /// Returns the key of the given row.
+    pub fn key(&self, row: &T) -> RowKey {
+        (self.0)(row)
+    }
+}
+
+impl<T> Clone for KeyExtractor<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for KeyExtractor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Returns the positions of the rows gathered by group, along with the key
+/// and the range of positions of each group.
+///
+/// The groups are ordered by their first row, and the rows of each group keep
+/// their given order.
+fn grouped_positions<T>(
+    rows: &[T],
+    group_by: &KeyExtractor<T>,
+    positions: Vec<usize>,
+) -> (Vec<usize>, Vec<(RowKey, Range<usize>)>) {
+    let mut indexes: HashMap<RowKey, usize> = HashMap::new();
+    let mut groups: Vec<(RowKey, Vec<usize>)> = Vec::new();
+    for position in positions {
+        let key = group_by.key(&rows[position]);
+        let index = *indexes.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[index].1.push(position);
+    }
+
+    let mut positions = Vec::new();
+    let ranges = groups
+        .into_iter()
+        .map(|(key, members)| {
+            let start = positions.len();
+            positions.extend(members);
+            (key, start..positions.len())
+        })
+        .collect();
+    (positions, ranges)
+}
+
+/// Returns the items of a [`DataTable`] holding its rows at the given
+/// positions, among the sorted, filtered and grouped ones.
+///
+/// Only the rows at the given positions are built, gathered under a
+/// [`TableGroup`] for each of the groups they belong to, if any, which counts
+/// all of the rows of its group.
+fn table_rows<F>(
+    groups: Option<Vec<(RowKey, Range<usize>)>>,
+    rendered_rows: Range<usize>,
+    row: F,
+) -> Vec<TableItem>
+where
+    F: Fn(usize) -> VChild<TableRow>,
+{
+    match groups {
+        Some(groups) => groups
+            .into_iter()
+            .filter_map(|(label, range)| {
+                let start = range.start.max(rendered_rows.start);
+                let end = range.end.min(rendered_rows.end);
+                (start < end).then(|| {
+                    html_nested! {
+                        <TableGroup key={label.to_string()} label={label.clone()} count={range.len()}>
+                            { for (start..end).map(&row) }
+                        </TableGroup>
+                    }
+                    .into()
+                })
+            })
+            .collect(),
+        None => rendered_rows.map(|index| row(index).into()).collect(),
+    }
+}
+
+/// Defines how the detail of each row of a [`DataTable`] is rendered.
+///
+/// Two renderers are equal if they share the same function.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::RowDetail;
+///
+/// struct User {
+///     name: String,
+///     bio: String,
+/// }
+///
+/// let detail = RowDetail::new(|user: &User| html! { user.bio.clone() });
+/// ```
+pub struct RowDetail<T>(Rc<dyn Fn(&T) -> Html>);
+
+impl<T> RowDetail<T> {
+    /// Creates a renderer from the given function.
+    pub fn new<F: Fn(&T) -> Html + 'static>(detail: F) -> Self {
+        Self(Rc::new(detail))
+    }
+
+    /// Returns the detail of the given row.
+    pub fn render(&self, row: &T) -> Html {
+        (self.0)(row)
+    }
+}
+
+impl<T> Clone for RowDetail<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for RowDetail<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The detail callbacks of the rows of a [`DataTable`], built once per row key
+/// for the rows and the renderer they were built from, so that unchanged rows
+/// keep equal properties between renders.
+struct RowDetails<T> {
+    rows: Rc<Vec<T>>,
+    detail: Option<RowDetail<T>>,
+    callbacks: HashMap<RowKey, Callback<(), Html>>,
+}
+
+impl<T: 'static> RowDetails<T> {
+    fn new(rows: Rc<Vec<T>>, detail: Option<RowDetail<T>>) -> Self {
+        Self {
+            rows,
+            detail,
+            callbacks: HashMap::new(),
+        }
+    }
+
+    /// Forgets the callbacks if the rows or the renderer changed.
+    fn update(&mut self, rows: &Rc<Vec<T>>, detail: &Option<RowDetail<T>>) {
+        if !Rc::ptr_eq(&self.rows, rows) || self.detail != *detail {
+            *self = Self::new(rows.clone(), detail.clone());
+        }
+    }
+
+    /// Returns the detail callback of the row with the given key and position.
+    fn callback(&mut self, key: &RowKey, position: usize) -> Option<Callback<(), Html>> {
+        let detail = self.detail.clone()?;
+        let rows = self.rows.clone();
+        let callback = self
+            .callbacks
+            .entry(key.clone())
+            .or_insert_with(|| Callback::from(move |_| detail.render(&rows[position])));
+        Some(callback.clone())
+    }
+}
+
+/// Defines how many rows of a [`DataTable`] can be selected.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, DataTable, SelectionMode};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![1, 2, 3]);
+///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+///
+///     html! {
+///         <DataTable<u32> {rows} {columns} selection={SelectionMode::Single} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    Single,
+    Multiple,
+}
+
+/// Returns the selection following a click on the checkbox of a row.
+///
+/// The rows are given by their keys, in the order in which they are shown. In
+/// single mode, the clicked row becomes the only selected one, or is
+/// unselected. In multiple mode, the clicked row is toggled and, when `range`
+/// is set, ie when the checkbox is shift-clicked, all of the rows between the
+/// `anchor`, ie the previously clicked row, and the clicked one take its new
+/// state.
+fn toggle_selection(
+    selection: &HashSet<RowKey>,
+    keys: &[RowKey],
+    clicked: usize,
+    anchor: Option<usize>,
+    mode: SelectionMode,
+    range: bool,
+) -> HashSet<RowKey> {
+    let key = &keys[clicked];
+    let select = !selection.contains(key);
+    if SelectionMode::Single == mode {
+        return if select {
+            HashSet::from([key.clone()])
+        } else {
+            HashSet::new()
+        };
+    }
+
+    let (start, end) = match anchor.filter(|anchor| range && *anchor < keys.len()) {
+        Some(anchor) => (anchor.min(clicked), anchor.max(clicked)),
+        None => (clicked, clicked),
+    };
+    let mut selection = selection.clone();
+    for key in &keys[start..=end] {
+        if select {
+            selection.insert(key.clone());
+        } else {
+            selection.remove(key);
+        }
+    }
+
+    selection
+}
+
+/// Returns the selection following a click on the select all checkbox.
+///
+/// All of the given rows are unselected if they are all already selected, and
+/// selected otherwise.
+fn toggle_all(selection: &HashSet<RowKey>, keys: &[RowKey]) -> HashSet<RowKey> {
+    let mut selection = selection.clone();
+    if keys.iter().all(|key| selection.contains(key)) {
+        for key in keys {
+            selection.remove(key);
+        }
+    } else {
+        selection.extend(keys.iter().cloned());
+    }
+
+    selection
+}
+
+/// Defines the properties of the data driven [Bulma table element][bd].
+///
+/// Defines the properties of a table element whose rows are rendered from a
+/// list of values, one cell for each of the given [`Column`]s, based on the
+/// specification found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, DataTable};
+///
+/// #[derive(PartialEq)]
+/// struct Player {
+///     name: String,
+///     points: u32,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![
+///         Player { name: "Ann".to_owned(), points: 12 },
+///         Player { name: "Bob".to_owned(), points: 7 },
+///     ]);
+///     let columns = vec![
+///         Column::new("Name", |player: &Player| html! { &player.name }),
+///         Column::new("Points", |player: &Player| html! { player.points }),
+///     ];
+///
+///     html! {
+///         <DataTable<Player> {rows} {columns} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DataTableProperties<T>
+where
+    T: PartialEq + 'static,
+{
+    /// The values rendered as the rows of the [Bulma table element][bd].
+    ///
+    /// Defines the values rendered as the rows of the [Bulma table element][bd]
+    /// which will receive these properties, one row for each value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec!["One", "Two", "Three"]);
+    ///     let columns = vec![Column::new("Number", |row: &&str| html! { *row })];
+    ///
+    ///     html! {
+    ///         <DataTable<&str> {rows} {columns} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub rows: Rc<Vec<T>>,
+    /// The columns of the [Bulma table element][bd].
+    ///
+    /// Defines the columns of the [Bulma table element][bd] which will receive
+    /// these properties. Each column renders its header, one cell for each row
+    /// and, if any column has one, its footer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![
+    ///         Column::new("Value", |row: &u32| html! { row }),
+    ///         Column::new("Double", |row: &u32| html! { row * 2 })
+    ///             .with_footer(|rows: &[&u32]| html! { rows.iter().map(|row| *row * 2).sum::<u32>() }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub columns: Vec<Column<T>>,
+    /// Sets the sort of the [Bulma table element][bd].
+    ///
+    /// Sets the columns by which the rows of the [Bulma table element][bd],
+    /// which will receive these properties, are sorted, by column id and in
+    /// order of priority. When set, the table is controlled and the sort only
+    /// changes through this property, usually from the `onsortchange`
+    /// callback. Otherwise, the table keeps track of its own sort.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, Direction};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![3, 1, 2]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let sort = use_state(|| vec![(AttrValue::from("Value"), Direction::Descending)]);
+    ///     let onsortchange = {
+    ///         let sort = sort.clone();
+    ///         Callback::from(move |value| sort.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} sort={(*sort).clone()} {onsortchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub sort: Option<Vec<(AttrValue, Direction)>>,
+    /// Sets the callback to be used when the sort of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when the header of a sortable column of
+    /// the [Bulma table element][bd], which will receive these properties, is
+    /// clicked. Clicking a header cycles its column through ascending,
+    /// descending and unsorted, while shift-clicking it keeps the other
+    /// sorted columns. The callback receives the new sort.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, Direction};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![3, 1, 2]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let sort = use_state(|| vec![(AttrValue::from("Value"), Direction::Descending)]);
+    ///     let onsortchange = {
+    ///         let sort = sort.clone();
+    ///         Callback::from(move |value| sort.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} sort={(*sort).clone()} {onsortchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onsortchange: Option<Callback<Vec<(AttrValue, Direction)>>>,
+    /// Whether or not the rows of the [Bulma table element][bd] are already sorted.
+    ///
+    /// Whether or not the rows of the [Bulma table element][bd], which will
+    /// receive these properties, are already sorted, ie by a server. If so,
+    /// the table only shows the sort indicators and emits the
+    /// `onsortchange` callback, without sorting the rows itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, Direction};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![3, 1, 2]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let onsortchange = Callback::from(|_sort: Vec<(AttrValue, Direction)>| {
+    ///         // Fetch the rows, sorted by the server.
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} {onsortchange} manual_sort=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub manual_sort: bool,
+    /// Sets the sort icons of the [Bulma table element][bd].
+    ///
+    /// Sets the callback rendering the icon shown in the header of each
+    /// sortable column of the [Bulma table element][bd] which will receive
+    /// these properties, given the direction of the column, if it is sorted.
+    /// The icon is wrapped in an [`Icon`] element. By default, the
+    /// [Font Awesome][fa] sort icons are used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, Direction};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![3, 1, 2]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let sort_icon = Callback::from(|direction: Option<Direction>| {
+    ///         let name = match direction {
+    ///             Some(Direction::Ascending) => "arrow_upward",
+    ///             Some(Direction::Descending) => "arrow_downward",
+    ///             None => "swap_vert",
+    ///         };
+    ///
+    ///         html! { <span class="material-symbols-outlined">{ name }</span> }
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} {sort_icon} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    /// [fa]: https://fontawesome.com/
+    #[prop_or_default]
+    pub sort_icon: Option<Callback<Option<Direction>, Html>>,
+    /// Sets the way the rows of the [Bulma table element][bd] are identified.
+    ///
+    /// Sets the [`KeyExtractor`] used to identify the rows of the
+    /// [Bulma table element][bd], which will receive these properties, when
+    /// they are selected. By default, rows are identified by their position in
+    /// `rows`, which only suits tables whose rows do not change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, KeyExtractor, RowKey, SelectionMode};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct User {
+    ///     id: u64,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![User { id: 7, name: "Ann" }, User { id: 9, name: "Bob" }]);
+    ///     let columns = vec![Column::new("Name", |user: &User| html! { user.name })];
+    ///     let row_key = KeyExtractor::new(|user: &User| AttrValue::from(user.id.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <DataTable<User> {rows} {columns} {row_key} selection={SelectionMode::Multiple}
+    ///             {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub row_key: Option<KeyExtractor<T>>,
+    /// Sets how many rows of the [Bulma table element][bd] can be selected.
+    ///
+    /// Sets how many rows of the [Bulma table element][bd], which will receive
+    /// these properties, can be selected. When set, a column of checkboxes is
+    /// added before the other columns and selected rows are marked as
+    /// such. In multiple mode, the header holds a checkbox selecting all of
+    /// the shown rows, ie of the current page of a [`PaginatedTable`], and
+    /// shift-clicking a checkbox selects all of the rows
+    /// between it and the previously clicked one. By default, rows cannot be
+    /// selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, KeyExtractor, RowKey, SelectionMode};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct User {
+    ///     id: u64,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![User { id: 7, name: "Ann" }, User { id: 9, name: "Bob" }]);
+    ///     let columns = vec![Column::new("Name", |user: &User| html! { user.name })];
+    ///     let row_key = KeyExtractor::new(|user: &User| AttrValue::from(user.id.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <DataTable<User> {rows} {columns} {row_key} selection={SelectionMode::Multiple}
+    ///             {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub selection: Option<SelectionMode>,
+    /// Sets the callback to be used when the selection of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when rows of the [Bulma table element][bd],
+    /// which will receive these properties, are selected or unselected. The
+    /// callback receives the keys of all of the selected rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, KeyExtractor, RowKey, SelectionMode};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct User {
+    ///     id: u64,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![User { id: 7, name: "Ann" }, User { id: 9, name: "Bob" }]);
+    ///     let columns = vec![Column::new("Name", |user: &User| html! { user.name })];
+    ///     let row_key = KeyExtractor::new(|user: &User| AttrValue::from(user.id.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <DataTable<User> {rows} {columns} {row_key} selection={SelectionMode::Multiple}
+    ///             {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onselectionchange: Option<Callback<HashSet<RowKey>>>,
+    /// Sets the detail of the rows of the [Bulma table element][bd].
+    ///
+    /// Sets the [`RowDetail`] of the rows of the [Bulma table element][bd]
+    /// which will receive these properties. When set, clicking a row toggles
+    /// a full width row below it, in which its detail is rendered. Detail rows
+    /// are not supported with virtual scrolling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, RowDetail};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct User {
+    ///     name: String,
+    ///     bio: String,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![User { name: "Ann".to_owned(), bio: "Admin since 2019.".to_owned() }]);
+    ///     let columns = vec![Column::new("Name", |user: &User| html! { user.name.clone() })];
+    ///     let detail = RowDetail::new(|user: &User| html! { user.bio.clone() });
+    ///
+    ///     html! {
+    ///         <DataTable<User> {rows} {columns} {detail} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub detail: Option<RowDetail<T>>,
+    /// Sets how the rows of the [Bulma table element][bd] are grouped.
+    ///
+    /// Sets the [`KeyExtractor`] used to group the rows of the
+    /// [Bulma table element][bd] which will receive these properties. When
+    /// set, the rows sharing the same key are gathered, once sorted and
+    /// filtered, under a [`TableGroup`] labelled with their key, in order of
+    /// their first row. Groups are not supported with virtual scrolling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable, KeyExtractor};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Player {
+    ///     name: String,
+    ///     team: String,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![
+    ///         Player { name: "Ann".to_owned(), team: "Red".to_owned() },
+    ///         Player { name: "Bob".to_owned(), team: "Blue".to_owned() },
+    ///         Player { name: "Cleo".to_owned(), team: "Red".to_owned() },
+    ///     ]);
+    ///     let columns = vec![Column::new("Name", |player: &Player| html! { player.name.clone() })];
+    ///     let group_by = KeyExtractor::new(|player: &Player| AttrValue::from(player.team.clone()));
+    ///
+    ///     html! {
+    ///         <DataTable<Player> {rows} {columns} {group_by} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub group_by: Option<KeyExtractor<T>>,
+    /// The range of positions, once sorted, of the shown rows.
+    ///
+    /// Internal to the [`PaginatedTable`], which only shows the rows of its
+    /// current page, and not meant to be set otherwise. The range is clamped
+    /// to the number of rows. Footers still take all of the filtered rows into
+    /// account, while selecting all of the rows only selects the shown ones.
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub visible_rows: Option<Range<usize>>,
+    /// Sets the virtual scrolling of the [Bulma table element][bd].
+    ///
+    /// Sets the [`VirtualScroll`] of the [Bulma table element][bd], which will
+    /// receive these properties, only rendering the rows visible in its
+    /// [table container][bd], as for a [`Table`]. Only the cells of those
+    /// rows are built. Only applies to scrollable tables, and is ignored when
+    /// the rows are grouped or have a detail, which virtual scrolling does not
+    /// support.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, DataTable},
+    ///     table::{RowHeight, VirtualScroll},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=50_000).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let virtual_scroll = VirtualScroll::new(RowHeight::Fixed(40.0));
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} scrollable=true {virtual_scroll} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScroll>,
+    /// Sets the filters of the [Bulma table element][bd].
+    ///
+    /// Sets the [`Filters`] of the [Bulma table element][bd] which will
+    /// receive these properties: its global search and the values of the
+    /// filters of its columns. When set, the filters only change through this
+    /// property, usually from the `onfilterchange` callback. Otherwise, the
+    /// table keeps track of its own filters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, DataTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let filters = use_state(|| Filters::default().with_search("an"));
+    ///     let onfilterchange = {
+    ///         let filters = filters.clone();
+    ///         Callback::from(move |value| filters.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <DataTable<(&str, u32)> {rows} {columns} filters={(*filters).clone()} {onfilterchange} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub filters: Option<Filters>,
+    /// Sets the callback to be used when the filters of the [Bulma table element][bd] change.
+    ///
+    /// Sets the callback to be used when the global search or the filter of a
+    /// column of the [Bulma table element][bd], which will receive these
+    /// properties, changes. The callback receives the new [`Filters`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, DataTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let filters = use_state(|| Filters::default().with_search("an"));
+    ///     let onfilterchange = {
+    ///         let filters = filters.clone();
+    ///         Callback::from(move |value| filters.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <DataTable<(&str, u32)> {rows} {columns} filters={(*filters).clone()} {onfilterchange} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onfilterchange: Option<Callback<Filters>>,
+    /// Whether or not the rows of the [Bulma table element][bd] are already filtered.
+    ///
+    /// Whether or not the rows of the [Bulma table element][bd], which will
+    /// receive these properties, are already filtered, ie by a server. If so,
+    /// the rows are shown as they are, and the filters are only
+    /// reported through the `onfilterchange` callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, DataTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let onfilterchange = Callback::from(|_filters: Filters| {
+    ///         // Fetch the rows, filtered by the server.
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<(&str, u32)> {rows} {columns} {onfilterchange} manual_filter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub manual_filter: bool,
+    /// The positions, in order, of the rows kept by the filters.
+    ///
+    /// Internal to the [`PaginatedTable`], which already filters the rows to
+    /// count its pages, and not meant to be set otherwise. The rows are then
+    /// not filtered again.
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub filtered_rows: Option<Rc<Vec<usize>>>,
+    /// Whether or not the [Bulma table element][bd] should have a search box.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be preceded by a search box, matching the text of all
+    /// of the columns with a text extractor, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, DataTable},
+    ///     table_filter::Filter,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<(&str, u32)> {rows} {columns} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub searchable: bool,
+    /// Whether or not the [Bulma table element][bd] should be scrollable.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be scrollable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} scrollable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub scrollable: bool,
+    /// Whether or not the [Bulma table element][bd] should be bordered.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be bordered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} bordered=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Whether or not the [Bulma table element][bd] should be striped.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be striped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} striped=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub striped: bool,
+    /// Whether or not the [Bulma table element][bd] should be narrow.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be narrow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} narrow=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Whether or not the [Bulma table element][bd] should be hoverable.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be hoverable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} hoverable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the [Bulma table element][bd] should be full width.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be full width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![1, 2, 3]);
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <DataTable<u32> {rows} {columns} full_width=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub full_width: bool,
+}
+
+/// Yew implementation of the data driven [Bulma table element][bd].
+///
+/// Yew implementation of a table element whose rows are rendered from a list
+/// of values, based on the specification found in the
+/// [Bulma table element documentation][bd]. It renders a [`Table`] with a
+/// [`TableHeader`] for each column, a [`TableRow`] for each row and, if any
+/// column has one, a [`TableFooter`] for each column.
+///
+/// Clicking the header of a column with a sort key cycles it through
+/// ascending, descending and unsorted, while shift-clicking it sorts by
+/// several columns at once. The sort is shown by an [`Icon`] in each sortable
+/// header, using the [Font Awesome][fa] icons unless `sort_icon` is set.
+///
+/// When `selection` is set, the table keeps track of the selected rows,
+/// through a column of checkboxes, and marks them as selected.
+///
+/// Columns with a [`Filter`] show its control in a [`TableHeaderRow`] below
+/// the headers, and `searchable` tables are preceded by a search box matching
+/// the text of all of the columns. Rows are filtered before being sorted, and
+/// both the footers and selecting all of the rows only take the rows kept by
+/// the filters into account, the latter only selecting the shown rows.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, DataTable};
+///
+/// #[derive(PartialEq)]
+/// struct Player {
+///     name: String,
+///     points: u32,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![
+///         Player { name: "Ann".to_owned(), points: 12 },
+///         Player { name: "Bob".to_owned(), points: 7 },
+///     ]);
+///     let columns = vec![
+///         Column::new("Name", |player: &Player| html! { &player.name }),
+///         Column::new("Points", |player: &Player| html! { player.points })
+///             .with_sort_key(|player: &Player| player.points)
+///             .with_footer(|players: &[&Player]| {
+///                 html! { players.iter().map(|player| player.points).sum::<u32>() }
+///             }),
+///     ];
+///
+///     html! {
+///         <DataTable<Player> {rows} {columns} striped=true full_width=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+/// [fa]: https://fontawesome.com/
+#[function_component(DataTable)]
+pub fn data_table<T>(props: &DataTableProperties<T>) -> Html
+where
+    T: PartialEq + 'static,
+{
+    let own_sort = use_state(Vec::new);
+    let sort = props.sort.clone().unwrap_or_else(|| (*own_sort).clone());
+    let onsort = {
+        let own_sort = own_sort.clone();
+        let controlled = props.sort.is_some();
+        let onsortchange = props.onsortchange.clone();
+        let sort = sort.clone();
+        move |column: AttrValue| {
+            let own_sort = own_sort.clone();
+            let onsortchange = onsortchange.clone();
+            let sort = sort.clone();
+            Callback::from(move |event: MouseEvent| {
+                let next = next_sort(&sort, &column, event.shift_key());
+                if !controlled {
+                    own_sort.set(next.clone());
+                }
+                if let Some(onsortchange) = &onsortchange {
+                    onsortchange.emit(next);
+                }
+            })
+        }
+    };
+
+    let own_filters = use_state(Filters::default);
+    let filters = props
+        .filters
+        .clone()
+        .unwrap_or_else(|| (*own_filters).clone());
+    let onfilterchange = {
+        let own_filters = own_filters.clone();
+        let controlled = props.filters.is_some();
+        let onfilterchange = props.onfilterchange.clone();
+        Callback::from(move |filters: Filters| {
+            if !controlled {
+                own_filters.set(filters.clone());
+            }
+            if let Some(onfilterchange) = &onfilterchange {
+                onfilterchange.emit(filters);
+            }
+        })
+    };
+
+    let headers = props.columns.iter().map(|column| {
+        if !column.is_sortable() {
+            return html_nested! {
+                <TableHeader>{ column.header().clone() }</TableHeader>
+            };
+        }
+
+        let position = sort.iter().position(|(id, _)| id == column.id());
+        let direction = position.map(|position| sort[position].1);
+        let color = direction.map_or(Some(TextColor::GreyLight), |_| None);
+        let icon = match &props.sort_icon {
+            Some(sort_icon) => sort_icon.emit(direction),
+            None => {
+                let icon = match direction {
+                    Some(Direction::Ascending) => "fa-sort-up",
+                    Some(Direction::Descending) => "fa-sort-down",
+                    None => "fa-sort",
+                };
+                html! { <i class={classes!("fas", icon)}></i> }
+            }
+        };
+        let priority = position
+            .filter(|_| sort.len() > 1)
+            .map(|position| html! { <sup>{ position + 1 }</sup> });
+        html_nested! {
+            <TableHeader class={classes!(IS_CLICKABLE)} onclick={onsort(column.id().clone())}>
+                { column.header().clone() }
+                <Icon {icon} {color} size={Size::Small} />
+                { priority.unwrap_or_default() }
+            </TableHeader>
+        }
+    });
+    let positions = match &props.filtered_rows {
+        Some(filtered_rows) => filtered_rows.to_vec(),
+        None if props.manual_filter => (0..props.rows.len()).collect(),
+        None => filtered_positions(
+            &props.rows,
+            &props.columns,
+            &filters,
+            (0..props.rows.len()).collect(),
+        ),
+    };
+    let positions = if props.manual_sort {
+        positions
+    } else {
+        sorted_positions(&props.rows, &props.columns, &sort, positions)
+    };
+    let (positions, groups) = match &props.group_by {
+        Some(group_by) => {
+            let (positions, groups) = grouped_positions(&props.rows, group_by, positions);
+            (positions, Some(groups))
+        }
+        None => (positions, None),
+    };
+    let filter_row = props.columns.iter().any(Column::has_filter).then(|| {
+        let controls = props.columns.iter().map(|column| {
+            let control = column.filter().map(|filter| {
+                let onchange = {
+                    let filters = filters.clone();
+                    let onfilterchange = onfilterchange.clone();
+                    let id = column.id().clone();
+                    Callback::from(move |value| {
+                        onfilterchange.emit(filters.clone().with_filter(id.clone(), value))
+                    })
+                };
+                filter.control(filters.filter(column.id()), onchange)
+            });
+            html! { <TableHeader>{ control.unwrap_or_default() }</TableHeader> }
+        });
+        html_nested! {
+            <TableHeaderRow>
+                if props.selection.is_some() {
+                    <TableHeader>{ Html::default() }</TableHeader>
+                }
+                { for controls }
+            </TableHeaderRow>
+        }
+    });
+    let search = props.searchable.then(|| {
+        let value = AttrValue::from(filters.search().to_owned());
+        let oninput = {
+            let filters = filters.clone();
+            let onfilterchange = onfilterchange.clone();
+            Callback::from(move |search: String| {
+                onfilterchange.emit(filters.clone().with_search(search))
+            })
+        };
+        html! {
+            <Field>
+                <Control>
+                    <Input input_type={InputType::Search} {value} placeholder="Search" {oninput} />
+                </Control>
+            </Field>
+        }
+    });
+    let keys: Rc<Vec<RowKey>> = Rc::new(
+        positions
+            .iter()
+            .map(|&position| match &props.row_key {
+                Some(row_key) => row_key.key(&props.rows[position]),
+                None => AttrValue::from(position.to_string()),
+            })
+            .collect(),
+    );
+
+    let own_selection = use_state(HashSet::new);
+    let anchor = use_state(|| None);
+    let onselectionchange = {
+        let own_selection = own_selection.clone();
+        let onselectionchange = props.onselectionchange.clone();
+        move |selection: HashSet<RowKey>| {
+            own_selection.set(selection.clone());
+            if let Some(onselectionchange) = &onselectionchange {
+                onselectionchange.emit(selection);
+            }
+        }
+    };
+    let onrowselect = |clicked: usize, mode: SelectionMode| {
+        let own_selection = own_selection.clone();
+        let anchor = anchor.clone();
+        let keys = keys.clone();
+        let onselectionchange = onselectionchange.clone();
+        Callback::from(move |event: MouseEvent| {
+            let selection = toggle_selection(
+                &own_selection,
+                &keys,
+                clicked,
+                *anchor,
+                mode,
+                event.shift_key(),
+            );
+            anchor.set(Some(clicked));
+            onselectionchange(selection);
+        })
+    };
+    let visible_rows = props
+        .visible_rows
+        .clone()
+        .map(|range| range.start.min(keys.len())..range.end.min(keys.len()))
+        .unwrap_or(0..keys.len());
+    // Selecting all of the rows only selects the shown ones, ie of the current
+    // page of a paginated table.
+    let selection_header = props.selection.map(|mode| {
+        let shown = &keys[visible_rows.clone()];
+        let all_selected = !shown.is_empty() && shown.iter().all(|key| own_selection.contains(key));
+        let onclick = {
+            let own_selection = own_selection.clone();
+            let keys = keys.clone();
+            let visible_rows = visible_rows.clone();
+            let onselectionchange = onselectionchange.clone();
+            Callback::from(move |_| {
+                onselectionchange(toggle_all(&own_selection, &keys[visible_rows.clone()]))
+            })
+        };
+        html_nested! {
+            <TableHeader>
+                if SelectionMode::Multiple == mode {
+                    <label class="checkbox">
+                        <input type="checkbox" checked={all_selected} {onclick} />
+                    </label>
+                }
+            </TableHeader>
+        }
+    });
+
+    let virtual_scroll = props
+        .virtual_scroll
+        .filter(|_| props.scrollable && props.group_by.is_none() && props.detail.is_none());
+    let window = use_state_eq(|| None::<Range<usize>>);
+    let onwindowchange = {
+        let window = window.clone();
+        Callback::from(move |rows| window.set(Some(rows)))
+    };
+    // Only the rows rendered by a virtualized table are built.
+    let rendered_rows = match virtual_scroll {
+        Some(virtual_scroll) => {
+            let window = (*window)
+                .clone()
+                .unwrap_or_else(|| initial_window(virtual_scroll, visible_rows.len()));
+            let start = (visible_rows.start + window.start).min(visible_rows.end);
+            let end = (visible_rows.start + window.end).min(visible_rows.end);
+            start..end
+        }
+        None => visible_rows.clone(),
+    };
+    let row_keys = virtual_scroll.map(|_| Rc::new(keys[visible_rows].to_vec()));
+    let details = use_mut_ref(|| RowDetails::new(props.rows.clone(), props.detail.clone()));
+    details.borrow_mut().update(&props.rows, &props.detail);
+    let row = |index: usize| {
+        let position = positions[index];
+        let selected = own_selection.contains(&keys[index]);
+        let checkbox = props.selection.map(|mode| {
+            let onclick = onrowselect(index, mode).reform(|event: MouseEvent| {
+                event.stop_propagation();
+                event
+            });
+            html! {
+                <TableData>
+                    <label class="checkbox">
+                        <input type="checkbox" checked={selected} {onclick} />
+                    </label>
+                </TableData>
+            }
+        });
+        let detail = details.borrow_mut().callback(&keys[index], position);
+        let row = &props.rows[position];
+        html_nested! {
+            <TableRow key={keys[index].to_string()} {selected} {detail}>
+                { for checkbox }
+                { for props.columns.iter().map(|column| html! {
+                    <TableData>{ column.cell(row) }</TableData>
+                }) }
+            </TableRow>
+        }
+    };
+    let rows = table_rows(groups, rendered_rows, row);
+    let has_footer = props.columns.iter().any(Column::has_footer);
+    let selection_footer = props
+        .selection
+        .filter(|_| has_footer)
+        .map(|_| html_nested! { <TableFooter>{ Html::default() }</TableFooter> });
+    // Footers aggregate the rows kept by the filters, wherever they are shown.
+    let footer_rows: Vec<&T> = if has_footer {
+        positions
+            .iter()
+            .map(|&position| &props.rows[position])
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let footers = props.columns.iter().filter(|_| has_footer).map(|column| {
+        html_nested! {
+            <TableFooter>{ column.footer(&footer_rows).unwrap_or_default() }</TableFooter>
+        }
+    });
+
+    html! {
+        <>
+            { search.unwrap_or_default() }
+            <Table id={props.id.clone()} class={props.class.clone()}
+                scrollable={props.scrollable} {virtual_scroll} {row_keys} {onwindowchange} bordered={props.bordered} striped={props.striped}
+                narrow={props.narrow} hoverable={props.hoverable} full_width={props.full_width}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for selection_header }
+                { for headers }
+                { for filter_row }
+                { for rows }
+                { for selection_footer }
+                { for footers }
+            </Table>
+        </>
+    }
+}
+
+/// The number of rows of a page of a [`PaginatedTable`], when no page sizes
+/// are given.
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// Returns the number of pages needed to show the given number of rows.
+///
+/// There is always at least one page, even when there are no rows.
+fn total_pages(total_rows: usize, page_size: usize) -> usize {
+    let page_size = page_size.max(1);
+
+    ((total_rows + page_size - 1) / page_size).max(1)
+}
+
+/// Returns the positions of the rows shown on the given page, starting at `1`.
+fn page_rows(page: usize, page_size: usize, total_rows: usize) -> Range<usize> {
+    let start = page
+        .saturating_sub(1)
+        .saturating_mul(page_size)
+        .min(total_rows);
+
+    start..start.saturating_add(page_size).min(total_rows)
+}
+
+/// Defines the properties of the paginated [Bulma table element][bd].
+///
+/// Defines the properties of a [`DataTable`] split into pages, followed by a
+/// [Bulma pagination component][pd] and a select of the number of rows per
+/// page, based on the specification found in the
+/// [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+///     let columns = vec![
+///         Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row),
+///         Column::new("Square", |row: &u32| html! { row * row }),
+///     ];
+///
+///     html! {
+///         <PaginatedTable<u32> {rows} {columns} striped=true full_width=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+/// [pd]: https://bulma.io/documentation/components/pagination/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PaginatedTableProperties<T>
+where
+    T: PartialEq + 'static,
+{
+    /// The values rendered as the rows of the [Bulma table element][bd].
+    ///
+    /// Defines the values rendered as the rows of the [Bulma table element][bd]
+    /// which will receive these properties. Unless `total_rows` is set, these
+    /// are all of the rows, which are split into pages by the table itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![
+    ///         Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row),
+    ///         Column::new("Square", |row: &u32| html! { row * row }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} striped=true full_width=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub rows: Rc<Vec<T>>,
+    /// The columns of the [Bulma table element][bd].
+    ///
+    /// Defines the columns of the [Bulma table element][bd] which will receive
+    /// these properties, as for a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![
+    ///         Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row),
+    ///         Column::new("Square", |row: &u32| html! { row * row }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} striped=true full_width=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub columns: Vec<Column<T>>,
+    /// Sets the current page of the [Bulma table element][bd].
+    ///
+    /// Sets the current page, starting from `1`, of the
+    /// [Bulma table element][bd] which will receive these properties. When
+    /// set, the page only changes through this property, usually from the
+    /// `onpagechange` callback. Otherwise, the table keeps track of its own
+    /// page. Pages out of bounds are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let page = use_state(|| 1);
+    ///     let onpagechange = {
+    ///         let page = page.clone();
+    ///         Callback::from(move |value: usize| page.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} page={*page} {onpagechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub page: Option<usize>,
+    /// Sets the callback to be used when the page of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when the page of the
+    /// [Bulma table element][bd], which will receive these properties,
+    /// changes, either from the pagination or because the page size, the sort
+    /// or the filters changed, which go back to the first page when not
+    /// already on it. The callback receives the new page, starting from `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let page = use_state(|| 1);
+    ///     let onpagechange = {
+    ///         let page = page.clone();
+    ///         Callback::from(move |value: usize| page.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} page={*page} {onpagechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onpagechange: Option<Callback<usize>>,
+    /// Sets the number of rows of each page of the [Bulma table element][bd].
+    ///
+    /// Sets the number of rows of each page of the [Bulma table element][bd]
+    /// which will receive these properties. When set, the page size only
+    /// changes through this property, usually from the `onpagesizechange`
+    /// callback. Otherwise, the table keeps track of its own page size,
+    /// starting with the first of the `page_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let page_size = use_state(|| 25);
+    ///     let onpagesizechange = {
+    ///         let page_size = page_size.clone();
+    ///         Callback::from(move |value: usize| page_size.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} page_size={*page_size} {onpagesizechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+    /// Sets the page sizes offered by the [Bulma table element][bd].
+    ///
+    /// Sets the page sizes offered by the select of the
+    /// [Bulma table element][bd] which will receive these properties. The
+    /// select is hidden when no page sizes are given. Defaults to `10`, `25`,
+    /// `50` and `100` rows per page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} page_sizes={vec![5, 20]} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or(vec![10, 25, 50, 100])]
+    pub page_sizes: Vec<usize>,
+    /// Sets the callback to be used when the page size of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when another page size is selected for the
+    /// [Bulma table element][bd] which will receive these properties. The
+    /// callback receives the new number of rows per page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let page_size = use_state(|| 25);
+    ///     let onpagesizechange = {
+    ///         let page_size = page_size.clone();
+    ///         Callback::from(move |value: usize| page_size.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} page_size={*page_size} {onpagesizechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onpagesizechange: Option<Callback<usize>>,
+    /// Sets the total number of rows of a server paged [Bulma table element][bd].
+    ///
+    /// Sets the total number of rows of the [Bulma table element][bd], which
+    /// will receive these properties, when they are paged by a server. If so,
+    /// `rows` only holds the rows of the current page, which are all shown,
+    /// and the number of pages is computed from the total number of rows.
+    /// As sorting or filtering a single page would be wrong, the rows of a
+    /// server paged table are also expected to be sorted and filtered by the
+    /// server, as with `manual_sort` and `manual_filter`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let page = use_state(|| 1);
+    ///     // Only the rows of the current page, as returned by the server.
+    ///     let rows = Rc::new(((*page * 10 - 9)..=(*page * 10)).collect::<Vec<usize>>());
+    ///     let columns = vec![Column::new("Value", |row: &usize| html! { row })];
+    ///     let onpagechange = {
+    ///         let page = page.clone();
+    ///         Callback::from(move |value: usize| page.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<usize> {rows} {columns} page={*page} page_size={10}
+    ///             total_rows={1000} {onpagechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub total_rows: Option<usize>,
+    /// Sets the sort of the [Bulma table element][bd].
+    ///
+    /// Sets the sort of the [Bulma table element][bd] which will receive these
+    /// properties, as for a [`DataTable`]. Rows are sorted before being split
+    /// into pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, Direction, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let sort = use_state(|| vec![(AttrValue::from("Value"), Direction::Descending)]);
+    ///     let onsortchange = {
+    ///         let sort = sort.clone();
+    ///         Callback::from(move |value| sort.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} sort={(*sort).clone()} {onsortchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub sort: Option<Vec<(AttrValue, Direction)>>,
+    /// Sets the callback to be used when the sort of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when the sort of the
+    /// [Bulma table element][bd], which will receive these properties,
+    /// changes, as for a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, Direction, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row)];
+    ///     let sort = use_state(|| vec![(AttrValue::from("Value"), Direction::Descending)]);
+    ///     let onsortchange = {
+    ///         let sort = sort.clone();
+    ///         Callback::from(move |value| sort.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} sort={(*sort).clone()} {onsortchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onsortchange: Option<Callback<Vec<(AttrValue, Direction)>>>,
+    /// Whether or not the rows of the [Bulma table element][bd] are already sorted.
+    ///
+    /// Whether or not the rows of the [Bulma table element][bd], which will
+    /// receive these properties, are already sorted, as for a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, Direction, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let onsortchange = Callback::from(|_sort: Vec<(AttrValue, Direction)>| {
+    ///         // Fetch the rows, sorted by the server.
+    ///     });
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {onsortchange} manual_sort=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub manual_sort: bool,
+    /// Sets the sort icons of the [Bulma table element][bd].
+    ///
+    /// Sets the callback rendering the sort icons of the
+    /// [Bulma table element][bd], which will receive these properties, as for
+    /// a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, Direction, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let sort_icon = Callback::from(|direction: Option<Direction>| match direction {
+    ///         Some(Direction::Ascending) => html! { "\u{2191}" },
+    ///         Some(Direction::Descending) => html! { "\u{2193}" },
+    ///         None => html! { "\u{2195}" },
+    ///     });
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {sort_icon} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub sort_icon: Option<Callback<Option<Direction>, Html>>,
+    /// Sets the way the rows of the [Bulma table element][bd] are identified.
+    ///
+    /// Sets the [`KeyExtractor`] used to identify the rows of the
+    /// [Bulma table element][bd], which will receive these properties, as for
+    /// a [`DataTable`]. It should be set for selectable server paged tables,
+    /// whose rows change from one page to another.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, KeyExtractor, RowKey, SelectionMode, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let row_key = KeyExtractor::new(|row: &u32| AttrValue::from(row.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {row_key} selection={SelectionMode::Multiple} {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub row_key: Option<KeyExtractor<T>>,
+    /// Sets how many rows of the [Bulma table element][bd] can be selected.
+    ///
+    /// Sets how many rows of the [Bulma table element][bd], which will receive
+    /// these properties, can be selected, as for a [`DataTable`]. The
+    /// selection is kept when changing pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, KeyExtractor, RowKey, SelectionMode, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let row_key = KeyExtractor::new(|row: &u32| AttrValue::from(row.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {row_key} selection={SelectionMode::Multiple} {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub selection: Option<SelectionMode>,
+    /// Sets the callback to be used when the selection of the [Bulma table element][bd] changes.
+    ///
+    /// Sets the callback to be used when the selection of the
+    /// [Bulma table element][bd], which will receive these properties,
+    /// changes, as for a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{collections::HashSet, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, KeyExtractor, RowKey, SelectionMode, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let row_key = KeyExtractor::new(|row: &u32| AttrValue::from(row.to_string()));
+    ///     let onselectionchange = Callback::from(|_selection: HashSet<RowKey>| {});
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {row_key} selection={SelectionMode::Multiple} {onselectionchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onselectionchange: Option<Callback<HashSet<RowKey>>>,
+    /// Sets the detail of the rows of the [Bulma table element][bd].
+    ///
+    /// Sets the [`RowDetail`] of the rows of the [Bulma table element][bd]
+    /// which will receive these properties, as for a [`DataTable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable, RowDetail};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let detail = RowDetail::new(|row: &u32| html! { format!("{} squared is {}", row, row * row) });
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {detail} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub detail: Option<RowDetail<T>>,
+    /// Sets how the rows of the [Bulma table element][bd] are grouped.
+    ///
+    /// Sets the [`KeyExtractor`] used to group the rows of the
+    /// [Bulma table element][bd], which will receive these properties, as for
+    /// a [`DataTable`]. The rows are grouped before being paged, so a group
+    /// may span several pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, KeyExtractor, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///     let group_by = KeyExtractor::new(|row: &u32| AttrValue::from(if row % 2 == 0 { "Even" } else { "Odd" }));
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} {group_by} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub group_by: Option<KeyExtractor<T>>,
+    /// Sets the filters of the [Bulma table element][bd].
+    ///
+    /// Sets the [`Filters`] of the [Bulma table element][bd] which will
+    /// receive these properties: its global search and the values of the
+    /// filters of its columns. When set, the filters only change through this
+    /// property, usually from the `onfilterchange` callback. Otherwise, the
+    /// table keeps track of its own filters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, PaginatedTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let filters = use_state(|| Filters::default().with_search("an"));
+    ///     let onfilterchange = {
+    ///         let filters = filters.clone();
+    ///         Callback::from(move |value| filters.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<(&str, u32)> {rows} {columns} filters={(*filters).clone()} {onfilterchange} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub filters: Option<Filters>,
+    /// Sets the callback to be used when the filters of the [Bulma table element][bd] change.
+    ///
+    /// Sets the callback to be used when the global search or the filter of a
+    /// column of the [Bulma table element][bd], which will receive these
+    /// properties, changes. The callback receives the new [`Filters`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, PaginatedTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let filters = use_state(|| Filters::default().with_search("an"));
+    ///     let onfilterchange = {
+    ///         let filters = filters.clone();
+    ///         Callback::from(move |value| filters.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PaginatedTable<(&str, u32)> {rows} {columns} filters={(*filters).clone()} {onfilterchange} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onfilterchange: Option<Callback<Filters>>,
+    /// Whether or not the rows of the [Bulma table element][bd] are already filtered.
+    ///
+    /// Whether or not the rows of the [Bulma table element][bd], which will
+    /// receive these properties, are already filtered, ie by a server. If so,
+    /// the rows are shown as they are, counting as many pages as the rows or
+    /// `total_rows` fill, and the filters are only reported through the
+    /// `onfilterchange` callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, PaginatedTable},
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///     let onfilterchange = Callback::from(|_filters: Filters| {
+    ///         // Fetch the rows, filtered by the server.
+    ///     });
+    ///
+    ///     html! {
+    ///         <PaginatedTable<(&str, u32)> {rows} {columns} {onfilterchange} manual_filter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub manual_filter: bool,
+    /// Whether or not the [Bulma table element][bd] should have a search box.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be preceded by a search box, matching the text of all
+    /// of the columns with a text extractor, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, PaginatedTable},
+    ///     table_filter::Filter,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<(&str, u32)> {rows} {columns} searchable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub searchable: bool,
+    /// Whether or not the [Bulma table element][bd] should be scrollable.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be scrollable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} scrollable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub scrollable: bool,
+    /// Whether or not the [Bulma table element][bd] should be bordered.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be bordered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} bordered=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Whether or not the [Bulma table element][bd] should be striped.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be striped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} striped=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub striped: bool,
+    /// Whether or not the [Bulma table element][bd] should be narrow.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be narrow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} narrow=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Whether or not the [Bulma table element][bd] should be hoverable.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be hoverable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} hoverable=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the [Bulma table element][bd] should be full width.
+    ///
+    /// Whether or not the [Bulma table element][bd], which will receive these
+    /// properties, will be full width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+    ///     let columns = vec![Column::new("Value", |row: &u32| html! { row })];
+    ///
+    ///     html! {
+    ///         <PaginatedTable<u32> {rows} {columns} full_width=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub full_width: bool,
+}
+
+/// Yew implementation of the paginated [Bulma table element][bd].
+///
+/// Yew implementation of a [`DataTable`] split into pages, based on the
+/// specification found in the [Bulma table element documentation][bd]. The
+/// table is followed by a [`Level`] holding a [`Select`] of the number of rows
+/// per page and a [`Pagination`] of the pages.
+///
+/// Rows are sorted and filtered before being split into pages, and going to
+/// another page size, sort or filter goes back to the first page. When
+/// `total_rows` is set, the rows are expected to be paged, sorted and filtered
+/// by a server, and are shown as they are.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, PaginatedTable};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new((1..=100).collect::<Vec<u32>>());
+///     let columns = vec![
+///         Column::new("Value", |row: &u32| html! { row }).with_sort_key(|row: &u32| *row),
+///         Column::new("Square", |row: &u32| html! { row * row }),
+///     ];
+///
+///     html! {
+///         <PaginatedTable<u32> {rows} {columns} striped=true full_width=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(PaginatedTable)]
+pub fn paginated_table<T>(props: &PaginatedTableProperties<T>) -> Html
+where
+    T: PartialEq + 'static,
+{
+    let own_page = use_state(|| 1);
+    let own_page_size = use_state(|| {
+        props
+            .page_sizes
+            .first()
+            .copied()
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    });
+    let own_filters = use_state(Filters::default);
+    let filters = props
+        .filters
+        .clone()
+        .unwrap_or_else(|| (*own_filters).clone());
+    let page_size = props.page_size.unwrap_or(*own_page_size).max(1);
+    // The rows kept by the filters are computed once, both to count the pages
+    // and to be shown by the data table.
+    let filtered_rows = (props.total_rows.is_none() && !props.manual_filter).then(|| {
+        Rc::new(filtered_positions(
+            &props.rows,
+            &props.columns,
+            &filters,
+            (0..props.rows.len()).collect(),
+        ))
+    });
+    let total_rows = props.total_rows.unwrap_or_else(|| {
+        filtered_rows
+            .as_ref()
+            .map_or(props.rows.len(), |filtered_rows| filtered_rows.len())
+    });
+    let total_pages = total_pages(total_rows, page_size);
+    let page = props.page.unwrap_or(*own_page).clamp(1, total_pages);
+
+    let onpagechange = {
+        let own_page = own_page.clone();
+        let controlled = props.page.is_some();
+        let onpagechange = props.onpagechange.clone();
+        Callback::from(move |page: usize| {
+            if !controlled {
+                own_page.set(page);
+            }
+            if let Some(onpagechange) = &onpagechange {
+                onpagechange.emit(page);
+            }
+        })
+    };
+    // Going back to the first page is only reported when not already on it.
+    let first_page = {
+        let onpagechange = onpagechange.clone();
+        move || {
+            if page != 1 {
+                onpagechange.emit(1);
+            }
+        }
+    };
+    let onpagesizechange = {
+        let own_page_size = own_page_size.clone();
+        let controlled = props.page_size.is_some();
+        let onpagesizechange = props.onpagesizechange.clone();
+        let first_page = first_page.clone();
+        Callback::from(move |page_size: usize| {
+            if !controlled {
+                own_page_size.set(page_size);
+            }
+            if let Some(onpagesizechange) = &onpagesizechange {
+                onpagesizechange.emit(page_size);
+            }
+            first_page();
+        })
+    };
+    let onsortchange = {
+        let onsortchange = props.onsortchange.clone();
+        let first_page = first_page.clone();
+        Callback::from(move |sort: Vec<(AttrValue, Direction)>| {
+            if let Some(onsortchange) = &onsortchange {
+                onsortchange.emit(sort);
+            }
+            first_page();
+        })
+    };
+    let onfilterchange = {
+        let controlled = props.filters.is_some();
+        let onfilterchange = props.onfilterchange.clone();
+        Callback::from(move |filters: Filters| {
+            if !controlled {
+                own_filters.set(filters.clone());
+            }
+            if let Some(onfilterchange) = &onfilterchange {
+                onfilterchange.emit(filters);
+            }
+            first_page();
+        })
+    };
+
+    let visible_rows = props
+        .total_rows
+        .is_none()
+        .then(|| page_rows(page, page_size, total_rows));
+    let options: Vec<_> = props
+        .page_sizes
+        .iter()
+        .map(|size| (*size, AttrValue::from(format!("{size} per page"))))
+        .collect();
+
+    html! {
+        <div id={&props.id} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <DataTable<T> rows={props.rows.clone()} columns={props.columns.clone()}
+                sort={props.sort.clone()} {onsortchange} manual_sort={props.manual_sort || props.total_rows.is_some()}
+                sort_icon={props.sort_icon.clone()} row_key={props.row_key.clone()}
+                selection={props.selection} onselectionchange={props.onselectionchange.clone()}
+                detail={props.detail.clone()} group_by={props.group_by.clone()}
+                {filters} {onfilterchange} manual_filter={props.manual_filter || props.total_rows.is_some()} {filtered_rows}
+                searchable={props.searchable} {visible_rows} scrollable={props.scrollable} bordered={props.bordered}
+                striped={props.striped} narrow={props.narrow} hoverable={props.hoverable}
+                full_width={props.full_width} />
+            <Level>
+                <LevelLeft>
+                    <LevelItem>
+                        if !options.is_empty() {
+                            <Select<usize> {options} value={page_size} onchange={onpagesizechange} />
+                        }
+                    </LevelItem>
+                </LevelLeft>
+                <LevelRight>
+                    <LevelItem>
+                        <Pagination current={page} {total_pages} {onpagechange} />
+                    </LevelItem>
+                </LevelRight>
+            </Level>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    fn total(rows: &[&u32]) -> Html {
+        html! { rows.iter().copied().sum::<u32>() }
+    }
+
+    #[test_case(vec![], "0" ; "no rows")]
+    #[test_case(vec![7], "7" ; "one row")]
+    #[test_case(vec![1, 2, 3], "6" ; "several rows")]
+    fn footer_aggregates_rows(rows: Vec<u32>, expected: &str) {
+        let column = Column::new("Value", |row: &u32| html! { row }).with_footer(total);
+
+        assert_eq!(
+            column.footer(&rows.iter().collect::<Vec<_>>()),
+            Some(html! { expected })
+        );
+    }
+
+    #[test]
+    fn footer_is_none_without_aggregate() {
+        let column = Column::new("Value", |row: &u32| html! { row });
+
+        assert!(!column.has_footer());
+        assert_eq!(column.footer(&[&1, &2, &3]), None);
+    }
+
+    #[test_case(vec![], vec![], vec![] ; "no rows")]
+    #[test_case(vec![0, 1, 2], vec![0, 1, 2], vec![("a", 0..2), ("b", 2..3)] ; "already grouped")]
+    #[test_case(vec![0, 2, 1, 3], vec![0, 1, 2, 3], vec![("a", 0..2), ("b", 2..4)] ; "interleaved")]
+    #[test_case(vec![3, 2, 1, 0], vec![3, 2, 1, 0], vec![("b", 0..2), ("a", 2..4)] ; "ordered by first row")]
+    #[test_case(vec![4, 0], vec![4, 0], vec![("c", 0..1), ("a", 1..2)] ; "filtered")]
+    fn grouped_positions_gathers_rows(
+        positions: Vec<usize>,
+        expected: Vec<usize>,
+        groups: Vec<(&'static str, Range<usize>)>,
+    ) {
+        let rows = ["a", "a", "b", "b", "c"];
+        let group_by = KeyExtractor::new(|row: &&str| AttrValue::from(*row));
+        let groups: Vec<(RowKey, Range<usize>)> = groups
+            .into_iter()
+            .map(|(key, range)| (AttrValue::from(key), range))
+            .collect();
+
+        assert_eq!(
+            grouped_positions(&rows, &group_by, positions),
+            (expected, groups)
+        );
+    }
+
+    fn counted_rows(
+        groups: Option<Vec<(RowKey, Range<usize>)>>,
+        rendered_rows: Range<usize>,
+    ) -> (usize, usize) {
+        let cells = Rc::new(std::cell::Cell::new(0));
+        let columns = ["Value", "Double"].map(|header| {
+            let cells = cells.clone();
+            Column::new(header, move |row: &u32| {
+                cells.set(cells.get() + 1);
+                html! { row }
+            })
+        });
+        let rows: Vec<u32> = (0..50_000).collect();
+        let items = table_rows(groups, rendered_rows, |index| {
+            html_nested! {
+                <TableRow>
+                    { for columns.iter().map(|column| html! {
+                        <TableData>{ column.cell(&rows[index]) }</TableData>
+                    }) }
+                </TableRow>
+            }
+        });
+
+        (items.len(), cells.get())
+    }
+
+    #[test]
+    fn table_rows_only_builds_rendered_rows() {
+        assert_eq!(counted_rows(None, 100..130), (30, 60));
+    }
+
+    #[test]
+    fn table_rows_only_builds_rendered_grouped_rows() {
+        let groups = vec![
+            (AttrValue::from("a"), 0..110),
+            (AttrValue::from("b"), 110..120),
+            (AttrValue::from("c"), 120..50_000),
+        ];
+
+        assert_eq!(counted_rows(Some(groups), 100..130), (3, 60));
+    }
+
+    #[test_case(0..5, vec![(5, 5)] ; "first rows of group")]
+    #[test_case(3..8, vec![(5, 2), (3, 3)] ; "split across groups")]
+    #[test_case(6..8, vec![(3, 2)] ; "last rows of group")]
+    fn table_rows_count_all_rows_of_groups(
+        rendered_rows: Range<usize>,
+        expected: Vec<(usize, usize)>,
+    ) {
+        let groups = vec![(AttrValue::from("a"), 0..5), (AttrValue::from("b"), 5..8)];
+        let items = table_rows(Some(groups), rendered_rows, |_| {
+            html_nested! { <TableRow><TableData>{ "row" }</TableData></TableRow> }
+        });
+        let counts: Vec<_> = items
+            .iter()
+            .map(|item| match item {
+                TableItem::TableGroup(group) => (
+                    group.props.count.unwrap_or_default(),
+                    group.props.children.len(),
+                ),
+                _ => (0, 0),
+            })
+            .collect();
+
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn row_details_are_kept_per_key() {
+        let rows = Rc::new(vec!["a", "b"]);
+        let detail = RowDetail::new(|row: &&str| html! { *row });
+        let mut details = RowDetails::new(rows.clone(), Some(detail.clone()));
+        let key = AttrValue::from("a");
+        let first = details.callback(&key, 0);
+
+        details.update(&rows, &Some(detail.clone()));
+        assert_eq!(details.callback(&key, 0), first);
+        assert_ne!(details.callback(&AttrValue::from("b"), 1), first);
+
+        details.update(&Rc::new(vec!["a", "b"]), &Some(detail));
+        assert_ne!(details.callback(&key, 0), first);
+
+        details.update(&rows, &None);
+        assert_eq!(details.callback(&key, 0), None);
+    }
+
+    fn sort(ids: &[(&'static str, Direction)]) -> Vec<(AttrValue, Direction)> {
+        ids.iter()
+            .map(|(id, direction)| (AttrValue::from(*id), *direction))
+            .collect()
+    }
+
+    #[test_case(&[], "a", false, &[("a", Direction::Ascending)] ; "unsorted to ascending")]
+    #[test_case(&[("a", Direction::Ascending)], "a", false, &[("a", Direction::Descending)] ; "ascending to descending")]
+    #[test_case(&[("a", Direction::Descending)], "a", false, &[] ; "descending to unsorted")]
+    #[test_case(&[("a", Direction::Ascending)], "b", false, &[("b", Direction::Ascending)] ; "replaces other column")]
+    #[test_case(&[("a", Direction::Ascending), ("b", Direction::Descending)], "b", false, &[] ; "single click clears others")]
+    #[test_case(&[("a", Direction::Ascending)], "b", true, &[("a", Direction::Ascending), ("b", Direction::Ascending)] ; "multiple appends column")]
+    #[test_case(&[("a", Direction::Ascending), ("b", Direction::Ascending)], "a", true, &[("a", Direction::Descending), ("b", Direction::Ascending)] ; "multiple keeps position")]
+    #[test_case(&[("a", Direction::Descending), ("b", Direction::Ascending)], "a", true, &[("b", Direction::Ascending)] ; "multiple removes column")]
+    fn next_sort_cycles_directions(
+        current: &[(&'static str, Direction)],
+        column: &str,
+        multiple: bool,
+        expected: &[(&'static str, Direction)],
+    ) {
+        assert_eq!(
+            next_sort(
+                &sort(current),
+                &AttrValue::from(column.to_owned()),
+                multiple
+            ),
+            sort(expected)
+        );
+    }
+
+    type Person = (&'static str, u32);
+
+    fn people() -> (Vec<Person>, Vec<Column<Person>>) {
+        let rows = vec![("Bob", 30), ("Ann", 25), ("Cid", 30), ("Ann", 40)];
+        let columns = vec![
+            Column::new("Name", |row: &Person| html! { row.0 }).with_sort_key(|row: &Person| row.0),
+            Column::new("Age", |row: &Person| html! { row.1 }).with_sort_key(|row: &Person| row.1),
+            Column::new("Other", |row: &Person| html! { row.1 }),
+        ];
+
+        (rows, columns)
+    }
+
+    #[test_case(&[], vec![0, 1, 2, 3] ; "unsorted")]
+    #[test_case(&[("Name", Direction::Ascending)], vec![1, 3, 0, 2] ; "ascending is stable")]
+    #[test_case(&[("Age", Direction::Descending)], vec![3, 0, 2, 1] ; "descending is stable")]
+    #[test_case(&[("Name", Direction::Ascending), ("Age", Direction::Descending)], vec![3, 1, 0, 2] ; "multiple columns")]
+    #[test_case(&[("Age", Direction::Ascending), ("Name", Direction::Descending)], vec![1, 2, 0, 3] ; "multiple columns reversed")]
+    #[test_case(&[("Other", Direction::Ascending)], vec![0, 1, 2, 3] ; "not sortable column")]
+    #[test_case(&[("Missing", Direction::Ascending)], vec![0, 1, 2, 3] ; "missing column")]
+    fn sorted_positions_orders_rows(current: &[(&'static str, Direction)], expected: Vec<usize>) {
+        let (rows, columns) = people();
+
+        assert_eq!(
+            sorted_positions(&rows, &columns, &sort(current), (0..rows.len()).collect()),
+            expected
+        );
+    }
+
+    fn keys(keys: &[&'static str]) -> Vec<RowKey> {
+        keys.iter().map(|key| AttrValue::from(*key)).collect()
+    }
+
+    fn selection(keys: &[&'static str]) -> HashSet<RowKey> {
+        keys.iter().map(|key| AttrValue::from(*key)).collect()
+    }
+
+    #[test_case(&[], 1, None, SelectionMode::Single, false, &["b"] ; "single selects")]
+    #[test_case(&["a"], 1, Some(0), SelectionMode::Single, false, &["b"] ; "single replaces")]
+    #[test_case(&["b"], 1, None, SelectionMode::Single, false, &[] ; "single unselects")]
+    #[test_case(&["a"], 3, Some(0), SelectionMode::Single, true, &["d"] ; "single ignores range")]
+    #[test_case(&["a"], 2, Some(0), SelectionMode::Multiple, false, &["a", "c"] ; "multiple adds")]
+    #[test_case(&["a", "c"], 0, Some(2), SelectionMode::Multiple, false, &["c"] ; "multiple unselects")]
+    #[test_case(&["a"], 3, Some(0), SelectionMode::Multiple, true, &["a", "b", "c", "d"] ; "range forwards")]
+    #[test_case(&[], 1, Some(3), SelectionMode::Multiple, true, &["b", "c", "d"] ; "range backwards")]
+    #[test_case(&["a", "b", "c", "d"], 2, Some(0), SelectionMode::Multiple, true, &["d"] ; "range unselects")]
+    #[test_case(&[], 2, None, SelectionMode::Multiple, true, &["c"] ; "range without anchor")]
+    #[test_case(&[], 1, Some(9), SelectionMode::Multiple, true, &["b"] ; "range with stale anchor")]
+    fn toggle_selection_updates_rows(
+        current: &[&'static str],
+        clicked: usize,
+        anchor: Option<usize>,
+        mode: SelectionMode,
+        range: bool,
+        expected: &[&'static str],
+    ) {
+        assert_eq!(
+            toggle_selection(
+                &selection(current),
+                &keys(&["a", "b", "c", "d"]),
+                clicked,
+                anchor,
+                mode,
+                range
+            ),
+            selection(expected)
+        );
+    }
+
+    #[test_case(&[], &["a", "b", "c"] ; "selects all")]
+    #[test_case(&["b"], &["a", "b", "c"] ; "selects remaining")]
+    #[test_case(&["a", "b", "c"], &[] ; "unselects all")]
+    #[test_case(&["a", "b", "c", "z"], &["z"] ; "keeps hidden rows")]
+    fn toggle_all_updates_rows(current: &[&'static str], expected: &[&'static str]) {
+        assert_eq!(
+            toggle_all(&selection(current), &keys(&["a", "b", "c"])),
+            selection(expected)
+        );
+    }
+
+    #[test]
+    fn columns_are_equal_when_sharing_renderers() {
+        let column = Column::new("Value", |row: &u32| html! { row }).with_footer(total);
+
+        assert!(column == column.clone());
+        assert!(column != Column::new("Value", |row: &u32| html! { row }).with_footer(total));
+    }
+
+    #[test_case(0, 10, 1 ; "no rows")]
+    #[test_case(9, 10, 1 ; "less than a page")]
+    #[test_case(10, 10, 1 ; "exactly a page")]
+    #[test_case(11, 10, 2 ; "more than a page")]
+    #[test_case(1000, 25, 40 ; "many pages")]
+    #[test_case(5, 0, 5 ; "empty pages")]
+    fn total_pages_counts_pages(total_rows: usize, page_size: usize, expected: usize) {
+        assert_eq!(total_pages(total_rows, page_size), expected);
+    }
+
+    #[test_case(1, 10, 25, 0..10 ; "first page")]
+    #[test_case(2, 10, 25, 10..20 ; "middle page")]
+    #[test_case(3, 10, 25, 20..25 ; "last page")]
+    #[test_case(4, 10, 25, 25..25 ; "page out of bounds")]
+    #[test_case(0, 10, 25, 0..10 ; "page zero")]
+    #[test_case(1, 10, 0, 0..0 ; "no rows")]
+    fn page_rows_slices_page(
+        page: usize,
+        page_size: usize,
+        total_rows: usize,
+        expected: Range<usize>,
+    ) {
+        assert_eq!(page_rows(page, page_size, total_rows), expected);
+    }
+}
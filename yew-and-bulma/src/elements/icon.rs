@@ -186,7 +186,7 @@ pub fn icon_text(props: &IconTextProperties) -> Html {
 ///
 /// [bd]: https://bulma.io/documentation/elements/icon/
 #[base_component_properties]
-#[derive(Properties, PartialEq)]
+#[derive(Clone, Properties, PartialEq)]
 pub struct IconProperties {
     /// Sets the text that should be displayed with the [icon element][bd].
     ///
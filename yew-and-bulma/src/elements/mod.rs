@@ -84,6 +84,35 @@ pub mod button;
 ///
 /// [bd]: https://bulma.io/documentation/elements/content/
 pub mod content;
+/// Provides utilities for creating data driven [table elements][bd] in Yew.
+///
+/// Defines the necessary components to build [Bulma table elements][bd] in
+/// Yew from a list of values and the columns used to render them.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::data_table::{Column, DataTable};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+///     let columns = vec![
+///         Column::new("Name", |row: &(&str, u32)| html! { row.0 }),
+///         Column::new("Points", |row: &(&str, u32)| html! { row.1 }),
+///     ];
+///
+///     html! {
+///         <DataTable<(&str, u32)> {rows} {columns} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+pub mod data_table;
 /// Provides utilities for creating [delete elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -231,6 +260,61 @@ pub mod progress;
 ///
 /// [bd]: https://bulma.io/documentation/elements/table/
 pub mod table;
+/// Provides utilities for exporting tables in Yew.
+///
+/// Defines the necessary functions and components to export tables built from
+/// typed rows and [`data_table::Column`]s as CSV or TSV, either downloading
+/// them or copying them to the clipboard.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{data_table::Column, table_export::to_csv};
+///
+/// let rows = vec![("Ann", 12), ("Bob", 7)];
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_text(|row: &(&str, u32)| row.1.to_string()),
+/// ];
+///
+/// assert_eq!(to_csv(&rows, &columns), "Name,Points\r\nAnn,12\r\nBob,7\r\n");
+/// ```
+pub mod table_export;
+/// Provides utilities for filtering tables in Yew.
+///
+/// Defines the necessary types to filter tables built from typed rows and
+/// [`data_table::Column`]s, by column and through a global search.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::{Column, DataTable},
+///     table_filter::Filter,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+///     let columns = vec![
+///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///             .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+///     ];
+///
+///     html! {
+///         <DataTable<(&str, u32)> {rows} {columns} searchable=true />
+///     }
+/// }
+/// ```
+pub mod table_filter;
 /// Provides utilities for creating [tag elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -1,4 +1,4 @@
-use yew::{function_component, html, Children, Html, Properties};
+use yew::{function_component, html, Callback, Children, Html, MouseEvent, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::{elements::delete::Delete, helpers::color::Color, utils::class::ClassBuilder};
@@ -92,6 +92,38 @@ pub struct NotificationProperties {
     /// [bd]: https://bulma.io/documentation/elements/notification/
     #[prop_or(true)]
     pub delete_button: bool,
+    /// Sets the callback to be used when the [notification][bd] is dismissed.
+    ///
+    /// Sets the callback to be used when the delete button of the
+    /// [Bulma notification element][bd], which will receive these properties,
+    /// is clicked. The notification does not hide itself, so the callback
+    /// should be used to stop rendering it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::notification::Notification;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let visible = use_state(|| true);
+    ///     let ondismiss = {
+    ///         let visible = visible.clone();
+    ///         Callback::from(move |_| visible.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         if *visible {
+    ///             <Notification {ondismiss}>{"Hello, world!"}</Notification>
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/notification/
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<()>>,
     /// The list of elements found inside the [notification element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -123,6 +155,12 @@ pub struct NotificationProperties {
 /// [bd]: https://bulma.io/documentation/elements/notification/
 #[function_component(Notification)]
 pub fn notification(props: &NotificationProperties) -> Html {
+    let ondelete = props.ondismiss.clone().map(|ondismiss| {
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            ondismiss.emit(());
+        })
+    });
     let class = ClassBuilder::default()
         .with_custom_class("notification")
         .with_color(props.color)
@@ -151,7 +189,7 @@ pub fn notification(props: &NotificationProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             if props.delete_button {
-                <Delete />
+                <Delete onclick={ondelete} />
             }
             { for props.children.iter() }
         </div>
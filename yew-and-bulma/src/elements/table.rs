@@ -1,9 +1,22 @@
-use yew::{function_component, html};
-use yew::{html::ChildrenRenderer, virtual_dom::VChild, AttrValue, Children, Html, Properties};
+use std::{collections::HashMap, ops::Range, rc::Rc};
+
+use web_sys::Element;
+use yew::{
+    classes, function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref, use_state,
+    Callback, ChildrenWithProps, Event, MouseEvent,
+};
+use yew::{
+    html::{ChildrenRenderer, TargetCast},
+    virtual_dom::{Key, VChild},
+    AttrValue, Children, Html, Properties,
+};
 use yew_and_bulma_macros::base_component_properties;
 
+use crate::elements::{icon::Icon, tag::Tag};
+use crate::helpers::spacing::{Direction, Spacing};
 use crate::utils::class::ClassBuilder;
-use crate::utils::constants::IS_NARROW;
+use crate::utils::constants::{IS_CLICKABLE, IS_NARROW};
+use crate::utils::size::Size;
 
 /// Defines the properties of the [Bulma table element][bd].
 ///
@@ -82,6 +95,127 @@ pub struct TableProperties {
     /// [bd]: https://bulma.io/documentation/elements/table/#table-container
     #[prop_or_default]
     pub scrollable: bool,
+    /// Sets the virtual scrolling of the [Bulma table element][bd].
+    ///
+    /// Sets the [`VirtualScroll`] of the [Bulma table element][bd], which will
+    /// receive these properties. When set, only the rows visible in the
+    /// [table container][bd] are rendered, along with a few more above and
+    /// below them, making very large tables usable. The rows which are not
+    /// rendered are replaced by empty rows of the same height. Only applies
+    /// to scrollable tables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0))}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (1..=10_000).map(|value| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ value }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScroll>,
+    /// Sets the keys of the rows of a virtualized [Bulma table element][bd].
+    ///
+    /// Sets the keys of all of the rows of the [Bulma table element][bd],
+    /// which will receive these properties, when it uses [`VirtualScroll`]
+    /// but its parent only builds the rows being rendered, as given to the
+    /// `onwindowchange` callback. The children of the table then only hold
+    /// those rows, the keys giving the number of rows of the table and
+    /// identifying the rows whose height is measured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{ops::Range, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let row_keys = use_memo(|_| (0..50_000).map(|row| AttrValue::from(row.to_string())).collect::<Vec<_>>(), ());
+    ///     let window = use_state(|| 0..0);
+    ///     let onwindowchange = {
+    ///         let window = window.clone();
+    ///         Callback::from(move |rows: Range<usize>| window.set(rows))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0))}
+    ///             row_keys={Rc::clone(&row_keys)} {onwindowchange}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (*window).clone().map(|row| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ row }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub row_keys: Option<Rc<Vec<AttrValue>>>,
+    /// Sets the callback to be used when the rendered rows of the [Bulma table element][bd] change.
+    ///
+    /// Sets the callback to be used when the rows rendered by the
+    /// [Bulma table element][bd], which will receive these properties, change
+    /// because it uses [`VirtualScroll`] and has been scrolled, resized or
+    /// given other rows. The callback receives the range of positions of the
+    /// rows to render, usually used along with `row_keys`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{ops::Range, rc::Rc};
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let row_keys = use_memo(|_| (0..50_000).map(|row| AttrValue::from(row.to_string())).collect::<Vec<_>>(), ());
+    ///     let window = use_state(|| 0..0);
+    ///     let onwindowchange = {
+    ///         let window = window.clone();
+    ///         Callback::from(move |rows: Range<usize>| window.set(rows))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0))}
+    ///             row_keys={Rc::clone(&row_keys)} {onwindowchange}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (*window).clone().map(|row| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ row }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub onwindowchange: Option<Callback<Range<usize>>>,
     /// Whether or not the [Bulma table element][bd] should be bordered.
     ///
     /// Whether or not the [Bulma table element][bd], which will receive these
@@ -345,8 +479,10 @@ impl From<&TableProperties> for String {
 #[derive(Clone, PartialEq)]
 pub enum TableItem {
     TableHeader(VChild<TableHeader>),
+    TableHeaderRow(VChild<TableHeaderRow>),
     TableFooter(VChild<TableFooter>),
     TableRow(VChild<TableRow>),
+    TableGroup(VChild<TableGroup>),
     TableData(VChild<TableData>),
 }
 
@@ -356,6 +492,11 @@ impl TableItem {
         matches!(self, TableItem::TableHeader(_))
     }
 
+    /// Determines if the table item is a [`crate::elements::table::TableHeaderRow`].
+    pub fn is_header_row(&self) -> bool {
+        matches!(self, TableItem::TableHeaderRow(_))
+    }
+
     /// Determines if the table item is a [`crate::elements::table::TableFooter`].
     pub fn is_footer(&self) -> bool {
         matches!(self, TableItem::TableFooter(_))
@@ -366,10 +507,21 @@ impl TableItem {
         matches!(self, TableItem::TableRow(_))
     }
 
+    /// Determines if the table item is a [`crate::elements::table::TableGroup`].
+    pub fn is_group(&self) -> bool {
+        matches!(self, TableItem::TableGroup(_))
+    }
+
     /// Determines if the table item is a [`crate::elements::table::TableData`].
     pub fn is_data(&self) -> bool {
         matches!(self, TableItem::TableData(_))
     }
+
+    /// Returns the key of the table item, if any.
+    fn key(&self) -> Option<Key> {
+        let html: Html = self.clone().into();
+        html.key().cloned()
+    }
 }
 
 impl From<VChild<TableHeader>> for TableItem {
@@ -378,6 +530,12 @@ impl From<VChild<TableHeader>> for TableItem {
     }
 }
 
+impl From<VChild<TableHeaderRow>> for TableItem {
+    fn from(value: VChild<TableHeaderRow>) -> Self {
+        TableItem::TableHeaderRow(value)
+    }
+}
+
 impl From<VChild<TableFooter>> for TableItem {
     fn from(value: VChild<TableFooter>) -> Self {
         TableItem::TableFooter(value)
@@ -390,6 +548,12 @@ impl From<VChild<TableRow>> for TableItem {
     }
 }
 
+impl From<VChild<TableGroup>> for TableItem {
+    fn from(value: VChild<TableGroup>) -> Self {
+        TableItem::TableGroup(value)
+    }
+}
+
 impl From<VChild<TableData>> for TableItem {
     fn from(value: VChild<TableData>) -> Self {
         TableItem::TableData(value)
@@ -401,13 +565,292 @@ impl Into<Html> for TableItem {
     fn into(self) -> Html {
         match self {
             TableItem::TableHeader(th) => th.into(),
+            TableItem::TableHeaderRow(tr) => tr.into(),
             TableItem::TableFooter(tf) => tf.into(),
             TableItem::TableRow(tr) => tr.into(),
+            TableItem::TableGroup(tg) => tg.into(),
             TableItem::TableData(td) => td.into(),
         }
     }
 }
 
+/// The number of rows rendered above and below the visible rows of a
+/// virtualized [`Table`], by default.
+const DEFAULT_OVERSCAN: usize = 5;
+
+/// The height, in pixels, of the container of a virtualized [`Table`], by
+/// default.
+const DEFAULT_HEIGHT: f64 = 400.0;
+
+/// Defines the height of the rows of a virtualized [Bulma table element][bd].
+///
+/// Defines how the height of the rows of a [`Table`] using [`VirtualScroll`]
+/// is known, in order to compute which rows are visible.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Measured(40.0))}>
+///             <TableHeader>{ "Value" }</TableHeader>
+///
+///             { for (1..=10_000).map(|value| html_nested! {
+///                 <TableRow>
+///                     <TableData>{ value }</TableData>
+///                 </TableRow>
+///             }) }
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All of the rows have the given height, in pixels.
+    Fixed(f64),
+    /// The rows are measured once rendered, using the given height, in
+    /// pixels, for the rows which were not rendered yet.
+    Measured(f64),
+}
+
+/// Defines the virtual scrolling of a [Bulma table element][bd].
+///
+/// Defines the virtual scrolling of a scrollable [`Table`], which only renders
+/// the rows visible in its [table container][bd], along with an overscan of
+/// rows above and below them. The container has a fixed height, which
+/// defaults to 400 pixels, and the overscan defaults to 5 rows.
+///
+/// Virtual scrolling expects each row of the table to render a single `<tr>`,
+/// so rows with a detail and [`TableGroup`]s are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0))}>
+///             <TableHeader>{ "Value" }</TableHeader>
+///
+///             { for (1..=10_000).map(|value| html_nested! {
+///                 <TableRow>
+///                     <TableData>{ value }</TableData>
+///                 </TableRow>
+///             }) }
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/#table-container
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualScroll {
+    row_height: RowHeight,
+    overscan: usize,
+    height: f64,
+}
+
+impl VirtualScroll {
+    /// Creates the virtual scrolling of a [Bulma table element][bd].
+    ///
+    /// Creates the virtual scrolling of a [`Table`] whose rows have the given
+    /// [`RowHeight`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table scrollable=true virtual_scroll={VirtualScroll::new(RowHeight::Fixed(40.0))}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (1..=10_000).map(|value| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ value }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    pub fn new(row_height: RowHeight) -> Self {
+        Self {
+            row_height,
+            overscan: DEFAULT_OVERSCAN,
+            height: DEFAULT_HEIGHT,
+        }
+    }
+
+    /// Sets the overscan of the virtual scrolling of a [Bulma table element][bd].
+    ///
+    /// Sets the number of rows rendered above and below the visible rows of
+    /// the [`Table`], which avoids showing empty rows when scrolling fast.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let virtual_scroll = VirtualScroll::new(RowHeight::Fixed(40.0)).with_overscan(20);
+    ///
+    ///     html! {
+    ///         <Table scrollable=true {virtual_scroll}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (1..=10_000).map(|value| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ value }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the height of the container of a virtualized [Bulma table element][bd].
+    ///
+    /// Sets the height, in pixels, of the [table container][bd] of the
+    /// [`Table`], in which its rows are scrolled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{RowHeight, Table, TableData, TableHeader, TableRow, VirtualScroll};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let virtual_scroll = VirtualScroll::new(RowHeight::Fixed(40.0)).with_height(600.0);
+    ///
+    ///     html! {
+    ///         <Table scrollable=true {virtual_scroll}>
+    ///             <TableHeader>{ "Value" }</TableHeader>
+    ///
+    ///             { for (1..=10_000).map(|value| html_nested! {
+    ///                 <TableRow>
+    ///                     <TableData>{ value }</TableData>
+    ///                 </TableRow>
+    ///             }) }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    pub fn with_height(mut self, height: f64) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+/// The rows of a virtualized [`Table`] which are rendered, along with the
+/// height of the rows which are not, above and below them.
+#[derive(Debug, PartialEq)]
+struct VirtualWindow {
+    rows: Range<usize>,
+    before: f64,
+    after: f64,
+}
+
+/// Identifies the rows of a virtualized [`Table`], so that the heights
+/// measured for them are dropped once they are given other rows.
+#[derive(PartialEq)]
+enum RowIdentities {
+    /// The keys of the rows found in the children of the table.
+    Children(Vec<Option<Key>>),
+    /// The keys of the rows given by the parent of the table.
+    Keys(Rc<Vec<AttrValue>>),
+}
+
+/// The heights measured for the rows of a virtualized [`Table`], by position,
+/// along with the rows they were measured for.
+struct MeasuredRows {
+    rows: RowIdentities,
+    heights: HashMap<usize, f64>,
+}
+
+/// Returns the rows of a virtualized [`Table`] with the given number of rows
+/// which are rendered before it is scrolled or measured.
+pub(crate) fn initial_window(virtual_scroll: VirtualScroll, count: usize) -> Range<usize> {
+    let row_height = match virtual_scroll.row_height {
+        RowHeight::Fixed(height) | RowHeight::Measured(height) => height,
+    };
+
+    virtual_window(
+        count,
+        |_| row_height,
+        0.0,
+        virtual_scroll.height,
+        virtual_scroll.overscan,
+    )
+    .rows
+}
+
+/// Returns the rows of a virtualized [`Table`] to render, given the height of
+/// each row, the scroll position and the height of the container.
+///
+/// The rows above the rendered ones are replaced by a single empty row, so
+/// the first rendered row always has an odd position, unless it is the first
+/// row, keeping the stripes of striped tables in place.
+fn virtual_window(
+    count: usize,
+    row_height: impl Fn(usize) -> f64,
+    scroll_top: f64,
+    height: f64,
+    overscan: usize,
+) -> VirtualWindow {
+    let mut first = count;
+    let mut last = count;
+    let mut top = 0.0;
+    for row in 0..count {
+        if top >= scroll_top + height {
+            last = row;
+            break;
+        }
+        let bottom = top + row_height(row);
+        if first == count && bottom > scroll_top {
+            first = row;
+        }
+        top = bottom;
+    }
+
+    let end = last.saturating_add(overscan).min(count);
+    let mut start = first.saturating_sub(overscan).min(end);
+    if start % 2 == 0 && start > 0 {
+        start -= 1;
+    }
+
+    VirtualWindow {
+        before: (0..start).map(&row_height).sum(),
+        after: (end..count).map(&row_height).sum(),
+        rows: start..end,
+    }
+}
+
 /// Yew implementation of the [Bulma table element][bd].
 ///
 /// Yew implementation of the table element, based on the specification found
@@ -457,13 +900,137 @@ pub fn table(props: &TableProperties) -> Html {
         )
         .build();
     let headers: Vec<_> = props.children.iter().filter(|ti| ti.is_header()).collect();
+    let header_rows: Vec<_> = props
+        .children
+        .iter()
+        .filter(|ti| ti.is_header_row())
+        .collect();
     let footers: Vec<_> = props.children.iter().filter(|ti| ti.is_footer()).collect();
     let data: Vec<_> = props
         .children
         .iter()
-        .filter(|ti| ti.is_row() || ti.is_data())
+        .filter(|ti| ti.is_row() || ti.is_group() || ti.is_data())
         .collect();
 
+    let body = use_node_ref();
+    let scroll_top = use_state(|| 0.0);
+    let container_height = use_state(|| None);
+    let virtual_scroll = props.virtual_scroll.filter(|_| props.scrollable);
+    let row_identities = match &props.row_keys {
+        Some(row_keys) => RowIdentities::Keys(row_keys.clone()),
+        None if virtual_scroll.is_some() => {
+            RowIdentities::Children(data.iter().map(TableItem::key).collect())
+        }
+        None => RowIdentities::Children(Vec::new()),
+    };
+    let count = props
+        .row_keys
+        .as_ref()
+        .map_or(data.len(), |keys| keys.len());
+    let measured = use_mut_ref(|| MeasuredRows {
+        rows: RowIdentities::Children(Vec::new()),
+        heights: HashMap::new(),
+    });
+    {
+        let mut measured = measured.borrow_mut();
+        if measured.rows != row_identities {
+            measured.rows = row_identities;
+            measured.heights.clear();
+        }
+    }
+    let window = virtual_scroll.map(|virtual_scroll| {
+        let measured = measured.borrow();
+        let row_height = |row: usize| match virtual_scroll.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(height) => measured.heights.get(&row).copied().unwrap_or(height),
+        };
+
+        virtual_window(
+            count,
+            row_height,
+            *scroll_top,
+            container_height.unwrap_or(virtual_scroll.height),
+            virtual_scroll.overscan,
+        )
+    });
+    {
+        let body = body.clone();
+        let measured = measured.clone();
+        let measuring = matches!(
+            virtual_scroll.map(|virtual_scroll| virtual_scroll.row_height),
+            Some(RowHeight::Measured(_))
+        );
+        let rows = window
+            .as_ref()
+            .filter(|_| measuring)
+            .map(|window| window.rows.clone());
+        use_effect_with_deps(
+            move |rows| {
+                if let (Some(rows), Some(body)) = (rows, body.cast::<Element>()) {
+                    let children = body.children();
+                    let offset = usize::from(rows.start > 0);
+                    let mut measured = measured.borrow_mut();
+                    for (index, row) in rows.clone().enumerate() {
+                        if let Some(element) = children.item((offset + index) as u32) {
+                            measured
+                                .heights
+                                .insert(row, element.get_bounding_client_rect().height());
+                        }
+                    }
+                }
+                || ()
+            },
+            rows,
+        );
+    }
+    {
+        let onwindowchange = props.onwindowchange.clone();
+        use_effect_with_deps(
+            move |rows| {
+                if let (Some(rows), Some(onwindowchange)) = (rows, &onwindowchange) {
+                    onwindowchange.emit(rows.clone());
+                }
+                || ()
+            },
+            window.as_ref().map(|window| window.rows.clone()),
+        );
+    }
+    let onscroll = {
+        let scroll_top = scroll_top.clone();
+        let container_height = container_height.clone();
+        let onscroll = props.onscroll.clone();
+        Callback::from(move |event: Event| {
+            let container = event.target_unchecked_into::<Element>();
+            scroll_top.set(f64::from(container.scroll_top()));
+            container_height.set(Some(f64::from(container.client_height())));
+            if let Some(onscroll) = &onscroll {
+                onscroll.emit(event);
+            }
+        })
+    };
+    let rows = match window {
+        Some(window) => {
+            // Rows given by the parent are already restricted to the window.
+            let skip = if props.row_keys.is_some() {
+                0
+            } else {
+                window.rows.start
+            };
+            html! {
+                <>
+                    if window.rows.start > 0 {
+                        <tr style={format!("height: {}px;", window.before)} aria-hidden="true" />
+                    }
+                    { for data.into_iter().skip(skip).take(window.rows.len()) }
+                    if window.rows.end < count {
+                        <tr style={format!("height: {}px;", window.after)} aria-hidden="true" />
+                    }
+                </>
+            }
+        }
+        None => html! { { for data } },
+    };
+
     let table_html = html! {
         <table id={props.id.clone()} {class}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
@@ -478,7 +1045,12 @@ pub fn table(props: &TableProperties) -> Html {
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            if !headers.is_empty() {
+            if !header_rows.is_empty() {
+                <thead>
+                    <tr>{ for headers }</tr>
+                    { for header_rows }
+                </thead>
+            } else if !headers.is_empty() {
                 <thead>
                     { for headers }
                 </thead>
@@ -490,15 +1062,20 @@ pub fn table(props: &TableProperties) -> Html {
                 </tfoot>
             }
 
-            <tbody>
-                { for data }
+            <tbody ref={body}>
+                { rows }
             </tbody>
         </table>
     };
 
     if props.scrollable {
+        let style = virtual_scroll.map(|virtual_scroll| {
+            format!("max-height: {}px; overflow-y: auto;", virtual_scroll.height)
+        });
+        let onscroll = virtual_scroll.map(|_| onscroll);
+
         html! {
-            <div class="table-container">
+            <div class="table-container" {style} {onscroll}>
                 {table_html}
             </div>
         }
@@ -815,36 +1392,146 @@ pub struct TableRowProperties {
     /// [bd]: https://bulma.io/documentation/elements/table/
     #[prop_or_default]
     pub selected: bool,
-    /// The list of elements found inside the [table row element][bd].
+    /// Sets the renderer of the detail of the [Bulma table row element][bd].
     ///
-    /// Defines the elements that will be found inside the
-    /// [Bulma table row element][bd] which will receive these properties.
+    /// Sets the callback rendering the detail of the
+    /// [Bulma table row element][bd] which will receive these properties. When
+    /// set, clicking the row toggles a second row, below it, holding a single
+    /// cell spanning all of its cells, in which the detail is rendered. The
+    /// detail is only rendered while the row is expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Role" }</TableHeader>
+    ///
+    ///             <TableRow detail={Callback::from(|_| html! { "Ann has been an admin since 2019." })}>
+    ///                 <TableData>{ "Ann" }</TableData>
+    ///                 <TableData>{ "Admin" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
     ///
     /// [bd]: https://bulma.io/documentation/elements/table/
-    pub children: Children,
-}
-
-impl From<&TableRowProperties> for String {
-    fn from(value: &TableRowProperties) -> Self {
-        if value.selected {
-            "is-selected".to_owned()
-        } else {
-            String::new()
-        }
-    }
-}
-
-/// Yew implementation of the [Bulma table row element][bd].
-///
-/// Yew implementation of the table row element, based on the specification
-/// found in the [Bulma table element documentation][bd].
-///
-/// # Examples
-///
-/// ```rust
-/// use yew::prelude::*;
-/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
-///
+    #[prop_or_default]
+    pub detail: Option<Callback<(), Html>>,
+    /// Sets whether or not the detail of the [Bulma table row element][bd] is shown.
+    ///
+    /// Sets whether or not the detail of the [Bulma table row element][bd],
+    /// which will receive these properties, is shown. When set, the row only
+    /// expands and collapses through this property, usually from the
+    /// `onexpandchange` callback. Otherwise, the row keeps track of its own
+    /// state, starting collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let expanded = use_state(|| true);
+    ///     let onexpandchange = {
+    ///         let expanded = expanded.clone();
+    ///         Callback::from(move |value| expanded.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Role" }</TableHeader>
+    ///
+    ///             <TableRow detail={Callback::from(|_| html! { "Ann has been an admin since 2019." })} expanded={*expanded} {onexpandchange}>
+    ///                 <TableData>{ "Ann" }</TableData>
+    ///                 <TableData>{ "Admin" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub expanded: Option<bool>,
+    /// Sets the callback to be used when the [Bulma table row element][bd] is expanded or collapsed.
+    ///
+    /// Sets the callback to be used when the [Bulma table row element][bd],
+    /// which will receive these properties, is clicked in order to show or
+    /// hide its detail. The callback receives whether or not the row should
+    /// be expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let expanded = use_state(|| true);
+    ///     let onexpandchange = {
+    ///         let expanded = expanded.clone();
+    ///         Callback::from(move |value| expanded.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Role" }</TableHeader>
+    ///
+    ///             <TableRow detail={Callback::from(|_| html! { "Ann has been an admin since 2019." })} expanded={*expanded} {onexpandchange}>
+    ///                 <TableData>{ "Ann" }</TableData>
+    ///                 <TableData>{ "Admin" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onexpandchange: Option<Callback<bool>>,
+    /// The list of elements found inside the [table row element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma table row element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: Children,
+}
+
+impl From<&TableRowProperties> for String {
+    fn from(value: &TableRowProperties) -> Self {
+        if value.selected {
+            "is-selected".to_owned()
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Yew implementation of the [Bulma table row element][bd].
+///
+/// Yew implementation of the table row element, based on the specification
+/// found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+///
 /// #[function_component(App)]
 /// fn app() -> Html {
 ///     html! {
@@ -872,8 +1559,157 @@ impl From<&TableRowProperties> for String {
 /// [bd]: https://bulma.io/documentation/elements/table/
 #[function_component(TableRow)]
 pub fn table_row(props: &TableRowProperties) -> Html {
+    let own_expanded = use_state(|| false);
+    let expanded = props.detail.is_some() && props.expanded.unwrap_or(*own_expanded);
     let class = ClassBuilder::default()
         .with_custom_class(&String::from(props))
+        .with_custom_class(if props.detail.is_some() {
+            IS_CLICKABLE
+        } else {
+            ""
+        })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let onclick = {
+        let has_detail = props.detail.is_some();
+        let controlled = props.expanded.is_some();
+        let onexpandchange = props.onexpandchange.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if has_detail {
+                if !controlled {
+                    own_expanded.set(!expanded);
+                }
+                if let Some(onexpandchange) = &onexpandchange {
+                    onexpandchange.emit(!expanded);
+                }
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let detail = props
+        .detail
+        .as_ref()
+        .filter(|_| expanded)
+        .map(|detail| detail.emit(()));
+    let colspan = props.children.len().max(1).to_string();
+
+    html! {
+        <>
+            <tr id={props.id.clone()} {class} {onclick}
+                onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </tr>
+            if let Some(detail) = detail {
+                <tr>
+                    <td {colspan}>{ detail }</td>
+                </tr>
+            }
+        </>
+    }
+}
+
+/// Defines the properties of the [Bulma table header row element][bd].
+///
+/// Defines the properties of an additional row of the head of the table,
+/// based on the specification found in the
+/// [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableHeaderRow, TableRow};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{ "One" }</TableHeader>
+///             <TableHeader>{ "Two" }</TableHeader>
+///             <TableHeaderRow>
+///                 <TableHeader>{ "Three" }</TableHeader>
+///                 <TableHeader>{ "Four" }</TableHeader>
+///             </TableHeaderRow>
+///
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableHeaderRowProperties {
+    /// The list of elements found inside the [table header row element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma table header row element][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma table header row element][bd].
+///
+/// Yew implementation of an additional row of the head of the table, rendered
+/// below its headers, such as a row of filters, based on the specification
+/// found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableHeaderRow, TableRow};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{ "One" }</TableHeader>
+///             <TableHeader>{ "Two" }</TableHeader>
+///             <TableHeaderRow>
+///                 <TableHeader>{ "Three" }</TableHeader>
+///                 <TableHeader>{ "Four" }</TableHeader>
+///             </TableHeaderRow>
+///
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(TableHeaderRow)]
+pub fn table_header_row(props: &TableHeaderRowProperties) -> Html {
+    let class = ClassBuilder::default()
         .with_custom_class(
             &props
                 .class
@@ -902,6 +1738,403 @@ pub fn table_row(props: &TableRowProperties) -> Html {
     }
 }
 
+/// Defines the properties of the [Bulma table group element][bd].
+///
+/// Defines the properties of a group of rows of the table element, based on
+/// the specification found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{ "Name" }</TableHeader>
+///             <TableHeader>{ "Points" }</TableHeader>
+///
+///             <TableGroup label="Red team">
+///                 <TableRow>
+///                     <TableData>{ "Ann" }</TableData>
+///                     <TableData>{ 12 }</TableData>
+///                 </TableRow>
+///                 <TableRow>
+///                     <TableData>{ "Bob" }</TableData>
+///                     <TableData>{ 7 }</TableData>
+///                 </TableRow>
+///             </TableGroup>
+///             <TableGroup label="Blue team">
+///                 <TableRow>
+///                     <TableData>{ "Cleo" }</TableData>
+///                     <TableData>{ 9 }</TableData>
+///                 </TableRow>
+///             </TableGroup>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableGroupProperties {
+    /// Sets the label of the [Bulma table group element][bd].
+    ///
+    /// Sets the label shown in the header row of the
+    /// [Bulma table group element][bd] which will receive these properties,
+    /// followed by the number of rows of the group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Points" }</TableHeader>
+    ///
+    ///             <TableGroup label="Red team">
+    ///                 <TableRow>
+    ///                     <TableData>{ "Ann" }</TableData>
+    ///                     <TableData>{ 12 }</TableData>
+    ///                 </TableRow>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Bob" }</TableData>
+    ///                     <TableData>{ 7 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///             <TableGroup label="Blue team">
+    ///                 <TableRow>
+    ///                     <TableData>{ "Cleo" }</TableData>
+    ///                     <TableData>{ 9 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub label: AttrValue,
+    /// Sets the number of rows of the [Bulma table group element][bd].
+    ///
+    /// Sets the number of rows shown in the header row of the
+    /// [Bulma table group element][bd], which will receive these properties,
+    /// when only some of its rows are given, ie when the table is paginated or
+    /// virtualized. Defaults to the number of given rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Points" }</TableHeader>
+    ///
+    ///             // The other 11 rows of the group are on other pages.
+    ///             <TableGroup label="Red team" count={12}>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Ann" }</TableData>
+    ///                     <TableData>{ 12 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub count: Option<usize>,
+    /// Sets whether or not the rows of the [Bulma table group element][bd] are shown.
+    ///
+    /// Sets whether or not the rows of the [Bulma table group element][bd],
+    /// which will receive these properties, are shown. When set, the group
+    /// only expands and collapses through this property, usually from the
+    /// `onexpandchange` callback. Otherwise, the group keeps track of its own
+    /// state, starting expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let expanded = use_state(|| false);
+    ///     let onexpandchange = {
+    ///         let expanded = expanded.clone();
+    ///         Callback::from(move |value| expanded.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Points" }</TableHeader>
+    ///
+    ///             <TableGroup label="Red team" expanded={*expanded} {onexpandchange}>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Ann" }</TableData>
+    ///                     <TableData>{ 12 }</TableData>
+    ///                 </TableRow>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Bob" }</TableData>
+    ///                     <TableData>{ 7 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///             <TableGroup label="Blue team">
+    ///                 <TableRow>
+    ///                     <TableData>{ "Cleo" }</TableData>
+    ///                     <TableData>{ 9 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub expanded: Option<bool>,
+    /// Sets the callback to be used when the [Bulma table group element][bd] is expanded or collapsed.
+    ///
+    /// Sets the callback to be used when the header row of the
+    /// [Bulma table group element][bd], which will receive these properties,
+    /// is clicked in order to show or hide its rows. The callback receives
+    /// whether or not the group should be expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let expanded = use_state(|| false);
+    ///     let onexpandchange = {
+    ///         let expanded = expanded.clone();
+    ///         Callback::from(move |value| expanded.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Points" }</TableHeader>
+    ///
+    ///             <TableGroup label="Red team" expanded={*expanded} {onexpandchange}>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Ann" }</TableData>
+    ///                     <TableData>{ 12 }</TableData>
+    ///                 </TableRow>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Bob" }</TableData>
+    ///                     <TableData>{ 7 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///             <TableGroup label="Blue team">
+    ///                 <TableRow>
+    ///                     <TableData>{ "Cleo" }</TableData>
+    ///                     <TableData>{ 9 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onexpandchange: Option<Callback<bool>>,
+    /// Sets the expansion icon of the [Bulma table group element][bd].
+    ///
+    /// Sets the callback rendering the icon shown in the header row of the
+    /// [Bulma table group element][bd], which will receive these properties,
+    /// given whether or not the group is expanded. The icon is wrapped in an
+    /// [`Icon`] element. By default, the [Font Awesome][fa] chevron icons are
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = Callback::from(|expanded: bool| {
+    ///         let name = if expanded { "expand_more" } else { "chevron_right" };
+    ///
+    ///         html! { <span class="material-symbols-outlined">{ name }</span> }
+    ///     });
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{ "Name" }</TableHeader>
+    ///             <TableHeader>{ "Points" }</TableHeader>
+    ///
+    ///             <TableGroup label="Red team" {icon}>
+    ///                 <TableRow>
+    ///                     <TableData>{ "Ann" }</TableData>
+    ///                     <TableData>{ 12 }</TableData>
+    ///                 </TableRow>
+    ///             </TableGroup>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    /// [fa]: https://fontawesome.com/
+    #[prop_or_default]
+    pub icon: Option<Callback<bool, Html>>,
+    /// The list of rows found inside the [table group element][bd].
+    ///
+    /// Defines the rows that will be found inside the
+    /// [Bulma table group element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: ChildrenWithProps<TableRow>,
+}
+
+/// Yew implementation of the [Bulma table group element][bd].
+///
+/// Yew implementation of a group of rows of the table element, based on the
+/// specification found in the [Bulma table element documentation][bd]. The
+/// group is rendered as a header row, spanning all of the cells of its first
+/// row and showing its label and number of rows, followed by its rows.
+/// Clicking the header row collapses or expands the rows of the group. By
+/// default, the header row uses the [Font Awesome][fa] chevron icons.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableData, TableGroup, TableHeader, TableRow};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{ "Name" }</TableHeader>
+///             <TableHeader>{ "Points" }</TableHeader>
+///
+///             <TableGroup label="Red team">
+///                 <TableRow>
+///                     <TableData>{ "Ann" }</TableData>
+///                     <TableData>{ 12 }</TableData>
+///                 </TableRow>
+///                 <TableRow>
+///                     <TableData>{ "Bob" }</TableData>
+///                     <TableData>{ 7 }</TableData>
+///                 </TableRow>
+///             </TableGroup>
+///             <TableGroup label="Blue team">
+///                 <TableRow>
+///                     <TableData>{ "Cleo" }</TableData>
+///                     <TableData>{ 9 }</TableData>
+///                 </TableRow>
+///             </TableGroup>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+/// [fa]: https://fontawesome.com/
+#[function_component(TableGroup)]
+pub fn table_group(props: &TableGroupProperties) -> Html {
+    let own_expanded = use_state(|| true);
+    let expanded = props.expanded.unwrap_or(*own_expanded);
+    let class = ClassBuilder::default()
+        .with_custom_class(IS_CLICKABLE)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let onclick = {
+        let controlled = props.expanded.is_some();
+        let onexpandchange = props.onexpandchange.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if !controlled {
+                own_expanded.set(!expanded);
+            }
+            if let Some(onexpandchange) = &onexpandchange {
+                onexpandchange.emit(!expanded);
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let colspan = props
+        .children
+        .iter()
+        .next()
+        .map_or(1, |row| row.props.children.len().max(1))
+        .to_string();
+    let icon = match &props.icon {
+        Some(icon) => icon.emit(expanded),
+        None => {
+            let icon = if expanded {
+                "fa-chevron-down"
+            } else {
+                "fa-chevron-right"
+            };
+            html! { <i class={classes!("fas", icon)}></i> }
+        }
+    };
+    let count = props.count.unwrap_or_else(|| props.children.len());
+    let count_class = ClassBuilder::default()
+        .with_margin(Direction::Left, Spacing::Two)
+        .build();
+
+    html! {
+        <>
+            <tr id={props.id.clone()} {class} {onclick}
+                onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                <th {colspan}>
+                    <Icon {icon} size={Size::Small} />
+                    { props.label.clone() }
+                    <Tag rounded=true class={count_class}>{ count }</Tag>
+                </th>
+            </tr>
+            if expanded {
+                { for props.children.iter() }
+            }
+        </>
+    }
+}
+
 /// Defines the properties of the [Bulma table data element][bd].
 ///
 /// Defines the properties of the table data element, based on the
@@ -1016,3 +2249,61 @@ pub fn table_data(props: &TableDataProperties) -> Html {
         </td>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(100, 0.0, 50.0, 0, 0..5, 0.0, 950.0 ; "top")]
+    #[test_case(100, 0.0, 50.0, 2, 0..7, 0.0, 930.0 ; "top with overscan")]
+    #[test_case(100, 200.0, 50.0, 0, 19..25, 190.0, 750.0 ; "middle on an even row")]
+    #[test_case(100, 205.0, 50.0, 0, 19..26, 190.0, 740.0 ; "middle within an even row")]
+    #[test_case(100, 215.0, 50.0, 0, 21..27, 210.0, 730.0 ; "middle within an odd row")]
+    #[test_case(100, 215.0, 50.0, 3, 17..30, 170.0, 700.0 ; "middle with overscan")]
+    #[test_case(100, 950.0, 50.0, 0, 95..100, 950.0, 0.0 ; "bottom")]
+    #[test_case(100, 2000.0, 50.0, 0, 99..100, 990.0, 0.0 ; "past the bottom")]
+    #[test_case(3, 0.0, 50.0, 5, 0..3, 0.0, 0.0 ; "fewer rows than visible")]
+    #[test_case(0, 0.0, 50.0, 5, 0..0, 0.0, 0.0 ; "no rows")]
+    fn virtual_window_with_fixed_heights(
+        count: usize,
+        scroll_top: f64,
+        height: f64,
+        overscan: usize,
+        rows: Range<usize>,
+        before: f64,
+        after: f64,
+    ) {
+        assert_eq!(
+            virtual_window(count, |_| 10.0, scroll_top, height, overscan),
+            VirtualWindow {
+                rows,
+                before,
+                after
+            }
+        );
+    }
+
+    #[test]
+    fn virtual_window_with_varying_heights() {
+        let row_height = |row: usize| if row % 2 == 0 { 10.0 } else { 30.0 };
+
+        assert_eq!(
+            virtual_window(10, row_height, 0.0, 40.0, 0),
+            VirtualWindow {
+                rows: 0..2,
+                before: 0.0,
+                after: 160.0
+            }
+        );
+        assert_eq!(
+            virtual_window(10, row_height, 45.0, 40.0, 0),
+            VirtualWindow {
+                rows: 1..5,
+                before: 10.0,
+                after: 110.0
+            }
+        );
+    }
+}
@@ -0,0 +1,972 @@
+use std::{borrow::Cow, rc::Rc};
+
+use gloo_timers::callback::Timeout;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    js_sys::Array,
+    wasm_bindgen::{JsCast, JsValue},
+    Blob, BlobPropertyBag, HtmlAnchorElement, Url,
+};
+use yew::{
+    function_component, html, platform::spawn_local, AttrValue, Callback, Children, Html,
+    MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        button::Button,
+        data_table::{sorted_positions, Column, Direction},
+        table_filter::{filtered_positions, Filters},
+    },
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+/// The UTF-8 byte order mark, which can be prepended to exported tables.
+///
+/// Spreadsheet applications, such as Excel, use it to detect that a CSV or TSV
+/// file is encoded in UTF-8. It is prepended by [`export`] when asked to, but
+/// never by [`to_csv`] and [`to_tsv`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::Column,
+///     table_export::{export, to_csv, ExportFormat, BOM},
+/// };
+///
+/// let rows = vec![("Ann", 12), ("Bob, Jr.", 7)];
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_text(|row: &(&str, u32)| row.1.to_string()),
+/// ];
+///
+/// assert_eq!(export(&rows, &columns, ExportFormat::Csv, true), format!("{BOM}{}", to_csv(&rows, &columns)));
+/// ```
+pub const BOM: &str = "\u{feff}";
+
+/// Defines the formats in which a table can be exported.
+///
+/// Defines the formats in which the rows of a table can be exported, all of
+/// them following the quoting rules of [RFC 4180][rfc].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::elements::table_export::ExportFormat;
+///
+/// assert_eq!(ExportFormat::Csv.delimiter(), ',');
+/// assert_eq!(ExportFormat::Tsv.extension(), "tsv");
+/// ```
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc4180
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values.
+    Csv,
+    /// Tab separated values.
+    Tsv,
+}
+
+impl ExportFormat {
+    /// Returns the character separating the fields of a record.
+    pub fn delimiter(&self) -> char {
+        match self {
+            ExportFormat::Csv => ',',
+            ExportFormat::Tsv => '\t',
+        }
+    }
+
+    /// Returns the extension of the files of this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Tsv => "tsv",
+        }
+    }
+
+    /// Returns the MIME type of the files of this format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+}
+
+/// Defines what can be done with an exported table.
+///
+/// Defines what a [`TableExportButton`] does with the table it exports.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::Column,
+///     table_export::{ExportAction, ExportFormat, TableExportButton},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+///     let columns = vec![
+///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+///     ];
+///
+///     html! {
+///         <TableExportButton<(&str, u32)> {rows} {columns} action={ExportAction::Clipboard} format={ExportFormat::Tsv} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportAction {
+    /// Downloads the table as a file.
+    Download,
+    /// Copies the table to the clipboard.
+    Clipboard,
+}
+
+/// Quotes a field if it contains the delimiter, a quote or a line break,
+/// doubling its quotes, as specified by RFC 4180.
+fn quote(field: &str, delimiter: char) -> Cow<'_, str> {
+    if field.contains([delimiter, '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Appends a record made of the given fields, ended by a line break.
+fn push_record<I>(output: &mut String, fields: I, delimiter: char)
+where
+    I: Iterator<Item = String>,
+{
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            output.push(delimiter);
+        }
+        output.push_str(&quote(&field, delimiter));
+    }
+    output.push_str("\r\n");
+}
+
+/// Exports the given rows, in order, in the given format, starting with a
+/// [`BOM`] if `bom` is set.
+fn export_rows<'a, T, I>(rows: I, columns: &[Column<T>], format: ExportFormat, bom: bool) -> String
+where
+    T: 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let delimiter = format.delimiter();
+    let columns: Vec<_> = columns.iter().filter(|column| column.has_text()).collect();
+    let mut output = String::new();
+
+    if bom {
+        output.push_str(BOM);
+    }
+
+    push_record(
+        &mut output,
+        columns.iter().map(|column| column.header().to_string()),
+        delimiter,
+    );
+    for row in rows {
+        push_record(
+            &mut output,
+            columns
+                .iter()
+                .map(|column| column.text(row).unwrap_or_default()),
+            delimiter,
+        );
+    }
+
+    output
+}
+
+/// Exports a table in the given format.
+///
+/// Exports the given rows, in order, as a table in the given
+/// [`ExportFormat`]. The first record holds the headers of the columns,
+/// followed by a record for each row. Only the columns with a text extractor,
+/// set through [`Column::with_text`], are exported. Records are ended by
+/// `CRLF` and fields are quoted as specified by [RFC 4180][rfc]. If `bom` is
+/// set, the table starts with a [`BOM`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{data_table::Column, table_export::{export, ExportFormat}};
+///
+/// let rows = vec![("Ann", 12), ("Bob, Jr.", 7)];
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_text(|row: &(&str, u32)| row.1.to_string()),
+/// ];
+///
+/// assert_eq!(export(&rows, &columns, ExportFormat::Tsv, false), "Name\tPoints\r\nAnn\t12\r\nBob, Jr.\t7\r\n");
+/// ```
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc4180
+pub fn export<T>(rows: &[T], columns: &[Column<T>], format: ExportFormat, bom: bool) -> String {
+    export_rows(rows.iter(), columns, format, bom)
+}
+
+/// Exports a table as comma separated values.
+///
+/// Exports the given rows as a table of comma separated values, as
+/// [`export`] does for [`ExportFormat::Csv`], without a [`BOM`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{data_table::Column, table_export::to_csv};
+///
+/// let rows = vec![("Ann", 12), ("Bob, Jr.", 7)];
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_text(|row: &(&str, u32)| row.1.to_string()),
+/// ];
+///
+/// assert_eq!(to_csv(&rows, &columns), "Name,Points\r\nAnn,12\r\n\"Bob, Jr.\",7\r\n");
+/// ```
+pub fn to_csv<T>(rows: &[T], columns: &[Column<T>]) -> String {
+    export(rows, columns, ExportFormat::Csv, false)
+}
+
+/// Exports a table as tab separated values.
+///
+/// Exports the given rows as a table of tab separated values, as [`export`]
+/// does for [`ExportFormat::Tsv`], without a [`BOM`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{data_table::Column, table_export::to_tsv};
+///
+/// let rows = vec![("Ann", 12), ("Bob, Jr.", 7)];
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_text(|row: &(&str, u32)| row.1.to_string()),
+/// ];
+///
+/// assert_eq!(to_tsv(&rows, &columns), "Name\tPoints\r\nAnn\t12\r\nBob, Jr.\t7\r\n");
+/// ```
+pub fn to_tsv<T>(rows: &[T], columns: &[Column<T>]) -> String {
+    export(rows, columns, ExportFormat::Tsv, false)
+}
+
+/// How long the object URL of a download is kept, in milliseconds, as some
+/// browsers cancel downloads whose URL is revoked while they start.
+const REVOKE_DELAY: u32 = 40_000;
+
+/// Describes a JavaScript error, as reported to the `onerror` callback of a
+/// [`TableExportButton`].
+fn describe(error: JsValue) -> String {
+    error
+        .dyn_ref::<web_sys::js_sys::Error>()
+        .map(|error| String::from(error.message()))
+        .or_else(|| error.as_string())
+        .unwrap_or_else(|| format!("{error:?}"))
+}
+
+/// Reports the given error to the given callback, or to the console if there
+/// is none.
+fn report(onerror: &Option<Callback<String>>, error: String) {
+    match onerror {
+        Some(onerror) => onerror.emit(error),
+        None => web_sys::console::error_1(&JsValue::from_str(&error)),
+    }
+}
+
+/// Downloads the given content as a file with the given name and MIME type.
+fn download(content: &str, file_name: &str, mime_type: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document to download from"))?;
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(
+        &Array::of1(&JsValue::from_str(content)),
+        &options,
+    )?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+    let anchor = document
+        .create_element("a")?
+        .unchecked_into::<HtmlAnchorElement>();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    Timeout::new(REVOKE_DELAY, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+
+    Ok(())
+}
+
+/// Copies the given content to the clipboard, reporting a failure to the
+/// given callback, or to the console if there is none.
+fn copy(content: &str, onerror: Option<Callback<String>>) {
+    let promise = match web_sys::window() {
+        Some(window) => window.navigator().clipboard().write_text(content),
+        None => return report(&onerror, "no window to copy from".to_owned()),
+    };
+    spawn_local(async move {
+        if let Err(error) = JsFuture::from(promise).await {
+            report(&onerror, describe(error));
+        }
+    });
+}
+
+/// Defines the properties of the table export [Bulma button element][bd].
+///
+/// Defines the properties of a [Bulma button element][bd] exporting a table
+/// built from typed rows and [`Column`]s, either downloading it or copying it
+/// to the clipboard.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::Column,
+///     table_export::TableExportButton,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+///     let columns = vec![
+///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+///     ];
+///
+///     html! {
+///         <TableExportButton<(&str, u32)> {rows} {columns} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableExportButtonProperties<T>
+where
+    T: PartialEq + 'static,
+{
+    /// The values exported as the rows of the table.
+    ///
+    /// Defines the values exported as the rows of the table by the
+    /// [Bulma button element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    pub rows: Rc<Vec<T>>,
+    /// The columns of the exported table.
+    ///
+    /// Defines the columns of the table exported by the
+    /// [Bulma button element][bd] which will receive these properties. Only
+    /// the columns with a text extractor are exported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    pub columns: Vec<Column<T>>,
+    /// Sets the sort of the exported table.
+    ///
+    /// Sets the sort of the table exported by the [Bulma button element][bd],
+    /// which will receive these properties, usually the sort of the
+    /// [`DataTable`](crate::elements::data_table::DataTable) showing the same
+    /// rows. By default, rows are exported in their given order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::{Column, Direction},
+    ///     table_export::TableExportButton,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_sort_key(|row: &(&str, u32)| row.1)
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///     let sort = vec![(AttrValue::from("Points"), Direction::Descending)];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} {sort} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub sort: Option<Vec<(AttrValue, Direction)>>,
+    /// Sets the filters of the exported table.
+    ///
+    /// Sets the [`Filters`] of the table exported by the
+    /// [Bulma button element][bd], which will receive these properties,
+    /// usually the filters of the
+    /// [`DataTable`](crate::elements::data_table::DataTable) showing the same
+    /// rows, so only the rows it shows are exported. By default, all of the
+    /// rows are exported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    ///     table_filter::{Filter, Filters},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned())
+    ///             .with_filter(Filter::text(|row: &(&str, u32)| row.0.to_owned())),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///     let filters = Filters::default().with_search("an");
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} {filters} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub filters: Option<Filters>,
+    /// Sets the format of the exported table.
+    ///
+    /// Sets the [`ExportFormat`] of the table exported by the
+    /// [Bulma button element][bd] which will receive these properties.
+    /// Defaults to [`ExportFormat::Csv`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::{ExportFormat, TableExportButton},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} format={ExportFormat::Tsv} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or(ExportFormat::Csv)]
+    pub format: ExportFormat,
+    /// Sets what is done with the exported table.
+    ///
+    /// Sets the [`ExportAction`] of the [Bulma button element][bd], which will
+    /// receive these properties, either downloading the exported table or
+    /// copying it to the clipboard. Defaults to [`ExportAction::Download`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::{ExportAction, TableExportButton},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} action={ExportAction::Clipboard} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or(ExportAction::Download)]
+    pub action: ExportAction,
+    /// Sets the name of the downloaded file.
+    ///
+    /// Sets the name of the file downloaded by the [Bulma button element][bd],
+    /// which will receive these properties, without its extension, which is
+    /// given by the format. Defaults to `table`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} file_name="points" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or(AttrValue::from("table"))]
+    pub file_name: AttrValue,
+    /// Whether or not the downloaded file should start with a byte order mark.
+    ///
+    /// Whether or not the file downloaded by the [Bulma button element][bd],
+    /// which will receive these properties, will start with a [`BOM`], which
+    /// helps spreadsheet applications detect that it is encoded in UTF-8.
+    /// Copies to the clipboard never start with one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} bom=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub bom: bool,
+    /// Sets the color of the [Bulma button element][bd].
+    ///
+    /// Sets the color of the [Bulma button element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    /// use yew_and_bulma::helpers::color::Color;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma button element][bd].
+    ///
+    /// Sets the size of the [Bulma button element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::TableExportButton,
+    /// };
+    /// use yew_and_bulma::utils::size::Size;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} size={Size::Small} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// The callback receiving the errors of the export.
+    ///
+    /// Defines the callback receiving the description of the errors
+    /// encountered by the [Bulma button element][bd], which will receive these
+    /// properties, while downloading the exported table or copying it to the
+    /// clipboard, for instance when the clipboard cannot be written to. By
+    /// default, errors are logged to the console.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{
+    ///     data_table::Column,
+    ///     table_export::{ExportAction, TableExportButton},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+    ///     let columns = vec![
+    ///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+    ///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+    ///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+    ///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+    ///     ];
+    ///     let onerror = Callback::from(|_error: String| {});
+    ///
+    ///     html! {
+    ///         <TableExportButton<(&str, u32)> {rows} {columns} action={ExportAction::Clipboard} {onerror} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub onerror: Option<Callback<String>>,
+    /// The content of the [Bulma button element][bd].
+    ///
+    /// Defines the content of the [Bulma button element][bd] which will
+    /// receive these properties. Defaults to a label describing its action.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the table export [Bulma button element][bd].
+///
+/// Yew implementation of a [Bulma button element][bd] exporting a table built
+/// from typed rows and [`Column`]s, as [`export`] does, when clicked. The
+/// exported table is either downloaded as a file or copied to the clipboard.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::Column,
+///     table_export::TableExportButton,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = Rc::new(vec![("Ann", 12), ("Bob", 7)]);
+///     let columns = vec![
+///         Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///             .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///         Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///             .with_text(|row: &(&str, u32)| row.1.to_string()),
+///     ];
+///
+///     html! {
+///         <TableExportButton<(&str, u32)> {rows} {columns} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/
+#[function_component(TableExportButton)]
+pub fn table_export_button<T>(props: &TableExportButtonProperties<T>) -> Html
+where
+    T: PartialEq + 'static,
+{
+    let onclick = {
+        let rows = props.rows.clone();
+        let columns = props.columns.clone();
+        let sort = props.sort.clone();
+        let filters = props.filters.clone();
+        let format = props.format;
+        let action = props.action;
+        let file_name = props.file_name.clone();
+        let bom = props.bom && action == ExportAction::Download;
+        let onerror = props.onerror.clone();
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            let content = if sort.is_none() && filters.is_none() {
+                export(&rows, &columns, format, bom)
+            } else {
+                let positions = (0..rows.len()).collect();
+                let positions = match &sort {
+                    Some(sort) => sorted_positions(&rows, &columns, sort, positions),
+                    None => positions,
+                };
+                let positions = match &filters {
+                    Some(filters) => filtered_positions(&rows, &columns, filters, positions),
+                    None => positions,
+                };
+                export_rows(
+                    positions.into_iter().map(|position| &rows[position]),
+                    &columns,
+                    format,
+                    bom,
+                )
+            };
+            match action {
+                ExportAction::Download => {
+                    let file_name = format!("{file_name}.{}", format.extension());
+                    if let Err(error) = download(&content, &file_name, format.mime_type()) {
+                        report(&onerror, describe(error));
+                    }
+                }
+                ExportAction::Clipboard => copy(&content, onerror.clone()),
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let label = match props.action {
+        ExportAction::Download => format!("Download {}", props.format.extension().to_uppercase()),
+        ExportAction::Clipboard => "Copy to clipboard".to_owned(),
+    };
+
+    html! {
+        <Button id={props.id.clone()} class={props.class.clone()} color={props.color}
+            size={props.size} {onclick}
+            onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if props.children.is_empty() {
+                { label }
+            } else {
+                { for props.children.iter() }
+            }
+        </Button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    type Person = (&'static str, &'static str);
+
+    fn columns() -> Vec<Column<Person>> {
+        vec![
+            Column::new("Name", |row: &Person| html! { row.0 })
+                .with_text(|row: &Person| row.0.to_owned()),
+            Column::new("Avatar", |_: &Person| html! { <img /> }),
+            Column::new("Note", |row: &Person| html! { row.1 })
+                .with_text(|row: &Person| row.1.to_owned()),
+        ]
+    }
+
+    #[test_case("plain", ',', "plain" ; "plain field")]
+    #[test_case("", ',', "" ; "empty field")]
+    #[test_case("a,b", ',', "\"a,b\"" ; "delimiter")]
+    #[test_case("a,b", '\t', "a,b" ; "other delimiter")]
+    #[test_case("a\tb", '\t', "\"a\tb\"" ; "tab delimiter")]
+    #[test_case("say \"hi\"", ',', "\"say \"\"hi\"\"\"" ; "quotes")]
+    #[test_case("one\ntwo", ',', "\"one\ntwo\"" ; "line feed")]
+    #[test_case("one\r\ntwo", ',', "\"one\r\ntwo\"" ; "carriage return")]
+    fn quote_follows_rfc_4180(field: &str, delimiter: char, expected: &str) {
+        assert_eq!(quote(field, delimiter), expected);
+    }
+
+    #[test_case(ExportFormat::Csv, "Name,Note\r\n" ; "csv")]
+    #[test_case(ExportFormat::Tsv, "Name\tNote\r\n" ; "tsv")]
+    fn export_without_rows_has_headers(format: ExportFormat, expected: &str) {
+        assert_eq!(export(&[], &columns(), format, false), expected);
+    }
+
+    #[test_case(false, "Name,Note\r\n" ; "without bom")]
+    #[test_case(true, "\u{feff}Name,Note\r\n" ; "with bom")]
+    fn export_starts_with_bom_if_set(bom: bool, expected: &str) {
+        assert_eq!(export(&[], &columns(), ExportFormat::Csv, bom), expected);
+    }
+
+    #[test]
+    fn export_skips_columns_without_text() {
+        let rows = vec![("Ann", "likes \"tea\""), ("Bob", "line\nbreak")];
+
+        assert_eq!(
+            to_csv(&rows, &columns()),
+            "Name,Note\r\nAnn,\"likes \"\"tea\"\"\"\r\nBob,\"line\nbreak\"\r\n"
+        );
+    }
+
+    #[test]
+    fn export_rows_keeps_given_order() {
+        let rows = [("Ann", "a"), ("Bob", "b")];
+
+        assert_eq!(
+            export_rows(rows.iter().rev(), &columns(), ExportFormat::Tsv, false),
+            "Name\tNote\r\nBob\tb\r\nAnn\ta\r\n"
+        );
+    }
+}
@@ -0,0 +1,683 @@
+use std::{collections::HashMap, rc::Rc};
+
+use yew::{function_component, html, use_state, AttrValue, Callback, Html, Properties};
+
+use crate::{
+    elements::data_table::Column,
+    form::{
+        general::{Control, Field},
+        input::{Input, InputType},
+        select::Select,
+    },
+    utils::size::Size,
+};
+
+/// Defines the value of the filter of a column of a [Bulma table element][bd].
+///
+/// Defines the value by which the rows of a
+/// [`DataTable`](crate::elements::data_table::DataTable) are filtered, for a
+/// column with a [`Filter`] of the same kind. Values of another kind than the
+/// filter of their column keep all of the rows.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table_filter::{FilterValue, Filters};
+///
+/// let filters = Filters::default()
+///     .with_filter("Name", FilterValue::Text("ann".to_owned()))
+///     .with_filter("Role", FilterValue::Options(vec![AttrValue::from("Admin")]))
+///     .with_filter("Age", FilterValue::Range(Some(18.0), None))
+///     .with_filter("Joined", FilterValue::DateRange(None, Some(AttrValue::from("2020-12-31"))));
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    /// Keeps the rows whose text contains the given text, ignoring case.
+    Text(String),
+    /// Keeps the rows whose option is one of the given options.
+    Options(Vec<AttrValue>),
+    /// Keeps the rows whose number is within the given inclusive bounds.
+    Range(Option<f64>, Option<f64>),
+    /// Keeps the rows whose date, formatted as `YYYY-MM-DD`, is within the
+    /// given inclusive bounds.
+    DateRange(Option<AttrValue>, Option<AttrValue>),
+}
+
+impl FilterValue {
+    /// Whether or not the value keeps all of the rows.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FilterValue::Text(text) => text.is_empty(),
+            FilterValue::Options(options) => options.is_empty(),
+            FilterValue::Range(min, max) => min.is_none() && max.is_none(),
+            FilterValue::DateRange(from, to) => from.is_none() && to.is_none(),
+        }
+    }
+}
+
+/// Defines the extraction of the value filtered by a [`Filter`] from a row.
+type Extractor<T, V> = Rc<dyn Fn(&T) -> V>;
+
+/// The kinds of [`Filter`], along with the extraction of their values.
+enum FilterKind<T> {
+    Text(Extractor<T, String>),
+    Options(Vec<AttrValue>, Extractor<T, AttrValue>),
+    Range(Extractor<T, f64>),
+    DateRange(Extractor<T, String>),
+}
+
+/// Defines the filter of a column of a [Bulma table element][bd].
+///
+/// Defines how the rows of a
+/// [`DataTable`](crate::elements::data_table::DataTable) can be filtered by a
+/// [`Column`], through the control shown below its header: a text, a multiple
+/// select of options, or a range of numbers or dates. The filter extracts the
+/// value it compares from each row.
+///
+/// Two filters are equal if they are of the same kind, with the same options,
+/// and share the same extractor.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{data_table::Column, table_filter::Filter};
+///
+/// #[derive(PartialEq)]
+/// struct Person {
+///     name: String,
+///     role: String,
+///     age: u32,
+///     joined: String,
+/// }
+///
+/// let column = Column::new("Role", |person: &Person| html! { &person.role })
+///     .with_filter(Filter::options(
+///         ["Admin", "Editor", "Viewer"],
+///         |person: &Person| person.role.clone(),
+///     ));
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+pub struct Filter<T>(FilterKind<T>);
+
+impl<T> Filter<T> {
+    /// Creates a filter keeping the rows whose extracted text contains the
+    /// filtered text, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{data_table::Column, table_filter::Filter};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Person {
+    ///     name: String,
+    ///     role: String,
+    ///     age: u32,
+    ///     joined: String,
+    /// }
+    ///
+    /// let column = Column::new("Name", |person: &Person| html! { &person.name })
+    ///     .with_filter(Filter::text(|person: &Person| person.name.clone()));
+    /// ```
+    pub fn text<F>(text: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        Self(FilterKind::Text(Rc::new(text)))
+    }
+
+    /// Creates a filter keeping the rows whose extracted option is one of the
+    /// selected options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{data_table::Column, table_filter::Filter};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Person {
+    ///     name: String,
+    ///     role: String,
+    ///     age: u32,
+    ///     joined: String,
+    /// }
+    ///
+    /// let column = Column::new("Role", |person: &Person| html! { &person.role })
+    ///     .with_filter(Filter::options(
+    ///         ["Admin", "Editor", "Viewer"],
+    ///         |person: &Person| person.role.clone(),
+    ///     ));
+    /// ```
+    pub fn options<I, O, V, F>(options: I, option: F) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<AttrValue>,
+        V: Into<AttrValue>,
+        F: Fn(&T) -> V + 'static,
+    {
+        Self(FilterKind::Options(
+            options.into_iter().map(Into::into).collect(),
+            Rc::new(move |row: &T| option(row).into()),
+        ))
+    }
+
+    /// Creates a filter keeping the rows whose extracted number is within the
+    /// filtered range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{data_table::Column, table_filter::Filter};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Person {
+    ///     name: String,
+    ///     role: String,
+    ///     age: u32,
+    ///     joined: String,
+    /// }
+    ///
+    /// let column = Column::new("Age", |person: &Person| html! { person.age })
+    ///     .with_filter(Filter::range(|person: &Person| f64::from(person.age)));
+    /// ```
+    pub fn range<F>(number: F) -> Self
+    where
+        F: Fn(&T) -> f64 + 'static,
+    {
+        Self(FilterKind::Range(Rc::new(number)))
+    }
+
+    /// Creates a filter keeping the rows whose extracted date, formatted as
+    /// `YYYY-MM-DD`, is within the filtered range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{data_table::Column, table_filter::Filter};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Person {
+    ///     name: String,
+    ///     role: String,
+    ///     age: u32,
+    ///     joined: String,
+    /// }
+    ///
+    /// let column = Column::new("Joined", |person: &Person| html! { &person.joined })
+    ///     .with_filter(Filter::date_range(|person: &Person| person.joined.clone()));
+    /// ```
+    pub fn date_range<F>(date: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        Self(FilterKind::DateRange(Rc::new(date)))
+    }
+
+    /// Whether or not the given row is kept by the filter for the given value.
+    pub fn matches(&self, row: &T, value: &FilterValue) -> bool {
+        match (&self.0, value) {
+            (FilterKind::Text(text), FilterValue::Text(filter)) => contains(&text(row), filter),
+            (FilterKind::Options(_, option), FilterValue::Options(options)) => {
+                options.is_empty() || options.contains(&option(row))
+            }
+            (FilterKind::Range(number), FilterValue::Range(min, max)) => {
+                let number = number(row);
+                min.map_or(true, |min| number >= min) && max.map_or(true, |max| number <= max)
+            }
+            (FilterKind::DateRange(date), FilterValue::DateRange(from, to)) => {
+                let date = date(row);
+                from.as_ref()
+                    .map_or(true, |from| date.as_str() >= from.as_str())
+                    && to.as_ref().map_or(true, |to| date.as_str() <= to.as_str())
+            }
+            _ => true,
+        }
+    }
+
+    /// Renders the control of the filter, showing the given value.
+    pub(crate) fn control(
+        &self,
+        value: Option<&FilterValue>,
+        onchange: Callback<FilterValue>,
+    ) -> Html {
+        match &self.0 {
+            FilterKind::Text(_) => {
+                let value = match value {
+                    Some(FilterValue::Text(text)) => AttrValue::from(text.clone()),
+                    _ => AttrValue::default(),
+                };
+                let oninput = onchange.reform(FilterValue::Text);
+
+                html! {
+                    <Input input_type={InputType::Search} size={Size::Small} {value}
+                        placeholder="Filter" {oninput} />
+                }
+            }
+            FilterKind::Options(options, _) => {
+                let values = match value {
+                    Some(FilterValue::Options(values)) => values.clone(),
+                    _ => Vec::new(),
+                };
+                let options: Vec<_> = options
+                    .iter()
+                    .map(|option| (option.clone(), option.clone()))
+                    .collect();
+                let onchangemultiple = onchange.reform(FilterValue::Options);
+
+                html! {
+                    <Select<AttrValue> {options} {values} multiple=true size={Size::Small}
+                        {onchangemultiple} />
+                }
+            }
+            FilterKind::Range(_) => {
+                let (min, max) = match value {
+                    Some(FilterValue::Range(min, max)) => (*min, *max),
+                    _ => (None, None),
+                };
+
+                html! { <RangeFilter {min} {max} {onchange} /> }
+            }
+            FilterKind::DateRange(_) => {
+                let (from, to) = match value {
+                    Some(FilterValue::DateRange(from, to)) => (from.clone(), to.clone()),
+                    _ => (None, None),
+                };
+                let onfrom = {
+                    let to = to.clone();
+                    onchange
+                        .reform(move |from: String| FilterValue::DateRange(date(from), to.clone()))
+                };
+                let onto = {
+                    let from = from.clone();
+                    onchange
+                        .reform(move |to: String| FilterValue::DateRange(from.clone(), date(to)))
+                };
+
+                html! {
+                    <Field addons=true>
+                        <Control>
+                            <Input input_type={InputType::Date} size={Size::Small}
+                                value={from.unwrap_or_default()} oninput={onfrom} />
+                        </Control>
+                        <Control>
+                            <Input input_type={InputType::Date} size={Size::Small}
+                                value={to.unwrap_or_default()} oninput={onto} />
+                        </Control>
+                    </Field>
+                }
+            }
+        }
+    }
+}
+
+impl<T> Clone for Filter<T> {
+    fn clone(&self) -> Self {
+        Self(match &self.0 {
+            FilterKind::Text(text) => FilterKind::Text(text.clone()),
+            FilterKind::Options(options, option) => {
+                FilterKind::Options(options.clone(), option.clone())
+            }
+            FilterKind::Range(number) => FilterKind::Range(number.clone()),
+            FilterKind::DateRange(date) => FilterKind::DateRange(date.clone()),
+        })
+    }
+}
+
+impl<T> PartialEq for Filter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (FilterKind::Text(a), FilterKind::Text(b)) => Rc::ptr_eq(a, b),
+            (FilterKind::Options(a, a_option), FilterKind::Options(b, b_option)) => {
+                a == b && Rc::ptr_eq(a_option, b_option)
+            }
+            (FilterKind::Range(a), FilterKind::Range(b)) => Rc::ptr_eq(a, b),
+            (FilterKind::DateRange(a), FilterKind::DateRange(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Whether or not the text contains the filter, ignoring case.
+fn contains(text: &str, filter: &str) -> bool {
+    text.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Returns the value of a number input showing the given number.
+fn number(number: Option<f64>) -> String {
+    number.map(|number| number.to_string()).unwrap_or_default()
+}
+
+/// Returns the number typed in a number input, if it is one.
+fn parse(text: &str) -> Option<f64> {
+    text.trim().parse().ok()
+}
+
+/// Returns the text of a number input bounding a range, keeping the typed text
+/// as long as it still gives the bound, ie while it is partially typed.
+fn bound_text(text: &str, bound: Option<f64>) -> String {
+    if parse(text) == bound {
+        text.to_owned()
+    } else {
+        number(bound)
+    }
+}
+
+/// Defines the properties of the control of a range filter.
+#[derive(Properties, PartialEq)]
+struct RangeFilterProperties {
+    min: Option<f64>,
+    max: Option<f64>,
+    onchange: Callback<FilterValue>,
+}
+
+/// Renders the control of a range filter, whose inputs keep their text as
+/// typed, only the numbers they hold being part of the filter value.
+#[function_component(RangeFilter)]
+fn range_filter(props: &RangeFilterProperties) -> Html {
+    let texts = use_state(|| (number(props.min), number(props.max)));
+    let min_text = bound_text(&texts.0, props.min);
+    let max_text = bound_text(&texts.1, props.max);
+    let onmin = {
+        let texts = texts.clone();
+        let max_text = max_text.clone();
+        let max = props.max;
+        let onchange = props.onchange.clone();
+        Callback::from(move |text: String| {
+            let min = parse(&text);
+            texts.set((text, max_text.clone()));
+            onchange.emit(FilterValue::Range(min, max));
+        })
+    };
+    let onmax = {
+        let min_text = min_text.clone();
+        let min = props.min;
+        let onchange = props.onchange.clone();
+        Callback::from(move |text: String| {
+            let max = parse(&text);
+            texts.set((min_text.clone(), text));
+            onchange.emit(FilterValue::Range(min, max));
+        })
+    };
+
+    html! {
+        <Field addons=true>
+            <Control>
+                <Input input_type={InputType::Number} size={Size::Small}
+                    value={min_text} placeholder="Min" oninput={onmin} />
+            </Control>
+            <Control>
+                <Input input_type={InputType::Number} size={Size::Small}
+                    value={max_text} placeholder="Max" oninput={onmax} />
+            </Control>
+        </Field>
+    }
+}
+
+/// Returns the date of a date input, if one is set.
+fn date(date: String) -> Option<AttrValue> {
+    Some(date)
+        .filter(|date| !date.is_empty())
+        .map(AttrValue::from)
+}
+
+/// Defines the filters of a [Bulma table element][bd].
+///
+/// Defines the filters of the rows of a
+/// [`DataTable`](crate::elements::data_table::DataTable): a global search,
+/// matching the text of all of the columns with a text extractor, and the
+/// [`FilterValue`] of each filtered column, by column id. Empty values are
+/// not kept.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{
+///     data_table::Column,
+///     table_filter::{Filter, FilterValue, Filters},
+/// };
+///
+/// let columns = vec![
+///     Column::new("Name", |row: &(&str, u32)| html! { row.0 })
+///         .with_text(|row: &(&str, u32)| row.0.to_owned()),
+///     Column::new("Points", |row: &(&str, u32)| html! { row.1 })
+///         .with_filter(Filter::range(|row: &(&str, u32)| f64::from(row.1))),
+/// ];
+/// let filters = Filters::default()
+///     .with_search("an")
+///     .with_filter("Points", FilterValue::Range(Some(10.0), None));
+///
+/// assert!(filters.matches(&("Ann", 12), &columns));
+/// assert!(!filters.matches(&("Ann", 7), &columns));
+/// assert!(!filters.matches(&("Bob", 12), &columns));
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filters {
+    search: String,
+    columns: HashMap<AttrValue, FilterValue>,
+}
+
+impl Filters {
+    /// Sets the global search, matching the text of all of the columns with a
+    /// text extractor, ignoring case.
+    pub fn with_search<S: Into<String>>(mut self, search: S) -> Self {
+        self.search = search.into();
+        self
+    }
+
+    /// Sets the value of the filter of the column with the given id, removing
+    /// it if the value is empty.
+    pub fn with_filter<I: Into<AttrValue>>(mut self, column: I, value: FilterValue) -> Self {
+        let column = column.into();
+        if value.is_empty() {
+            self.columns.remove(&column);
+        } else {
+            self.columns.insert(column, value);
+        }
+        self
+    }
+
+    /// Returns the global search.
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Returns the value of the filter of the column with the given id, if it
+    /// has one.
+    pub fn filter(&self, column: &str) -> Option<&FilterValue> {
+        self.columns.get(column)
+    }
+
+    /// Returns the values of the filters of the columns, by column id.
+    pub fn columns(&self) -> impl Iterator<Item = (&AttrValue, &FilterValue)> {
+        self.columns.iter()
+    }
+
+    /// Whether or not there is neither a global search nor a filtered column.
+    pub fn is_empty(&self) -> bool {
+        self.search.is_empty() && self.columns.is_empty()
+    }
+
+    /// Whether or not the given row is kept by the filters, given the columns
+    /// of its table.
+    ///
+    /// Filters of columns which are not found or have no filter keep all of
+    /// the rows.
+    pub fn matches<T>(&self, row: &T, columns: &[Column<T>]) -> bool {
+        let search = self.search.trim();
+        let found = search.is_empty()
+            || columns
+                .iter()
+                .filter_map(|column| column.text(row))
+                .any(|text| contains(&text, search));
+
+        found
+            && self.columns.iter().all(|(id, value)| {
+                columns
+                    .iter()
+                    .find(|column| column.id() == id)
+                    .and_then(Column::filter)
+                    .map_or(true, |filter| filter.matches(row, value))
+            })
+    }
+}
+
+/// Returns the given positions of the rows kept by the filters, in order.
+pub(crate) fn filtered_positions<T>(
+    rows: &[T],
+    columns: &[Column<T>],
+    filters: &Filters,
+    positions: Vec<usize>,
+) -> Vec<usize> {
+    if filters.is_empty() {
+        return positions;
+    }
+
+    positions
+        .into_iter()
+        .filter(|&position| filters.matches(&rows[position], columns))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    type Person = (&'static str, &'static str, u32, &'static str);
+
+    fn columns() -> Vec<Column<Person>> {
+        vec![
+            Column::new("Name", |row: &Person| html! { row.0 })
+                .with_text(|row: &Person| row.0.to_owned())
+                .with_filter(Filter::text(|row: &Person| row.0.to_owned())),
+            Column::new("Role", |row: &Person| html! { row.1 })
+                .with_text(|row: &Person| row.1.to_owned())
+                .with_filter(Filter::options(["Admin", "Editor"], |row: &Person| row.1)),
+            Column::new("Age", |row: &Person| html! { row.2 })
+                .with_filter(Filter::range(|row: &Person| f64::from(row.2))),
+            Column::new("Joined", |row: &Person| html! { row.3 })
+                .with_filter(Filter::date_range(|row: &Person| row.3.to_owned())),
+        ]
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            ("Ann", "Admin", 34, "2019-04-01"),
+            ("Bob", "Editor", 27, "2021-09-15"),
+            ("Cleo", "Viewer", 45, "2020-01-31"),
+            ("Dan", "Editor", 19, "2023-06-30"),
+        ]
+    }
+
+    fn options(options: &[&'static str]) -> FilterValue {
+        FilterValue::Options(options.iter().copied().map(AttrValue::from).collect())
+    }
+
+    fn dates(from: Option<&'static str>, to: Option<&'static str>) -> FilterValue {
+        FilterValue::DateRange(from.map(AttrValue::from), to.map(AttrValue::from))
+    }
+
+    #[test_case(FilterValue::Text(String::new()), true ; "empty text")]
+    #[test_case(FilterValue::Text("a".to_owned()), false ; "text")]
+    #[test_case(options(&[]), true ; "no options")]
+    #[test_case(options(&["Admin"]), false ; "options")]
+    #[test_case(FilterValue::Range(None, None), true ; "unbounded range")]
+    #[test_case(FilterValue::Range(None, Some(1.0)), false ; "range")]
+    #[test_case(dates(None, None), true ; "unbounded dates")]
+    #[test_case(dates(Some("2020-01-01"), None), false ; "dates")]
+    fn filter_value_is_empty(value: FilterValue, expected: bool) {
+        assert_eq!(value.is_empty(), expected);
+    }
+
+    #[test_case("", None, "" ; "empty")]
+    #[test_case("5", Some(5.0), "5" ; "number")]
+    #[test_case(" 5", Some(5.0), " 5" ; "number with spaces")]
+    #[test_case("-", None, "-" ; "partial negative number")]
+    #[test_case("1e", None, "1e" ; "partial exponent")]
+    #[test_case("5", None, "" ; "cleared bound")]
+    #[test_case("5", Some(7.0), "7" ; "changed bound")]
+    #[test_case("-", Some(2.5), "2.5" ; "set bound")]
+    fn bound_text_keeps_typed_text(text: &str, bound: Option<f64>, expected: &str) {
+        assert_eq!(bound_text(text, bound), expected);
+    }
+
+    #[test_case("Name", FilterValue::Text("AN".to_owned()), vec![0, 3] ; "text ignoring case")]
+    #[test_case("Name", FilterValue::Text("zed".to_owned()), vec![] ; "text without match")]
+    #[test_case("Role", options(&["Editor"]), vec![1, 3] ; "one option")]
+    #[test_case("Role", options(&["Admin", "Viewer"]), vec![0, 2] ; "several options")]
+    #[test_case("Age", FilterValue::Range(Some(27.0), None), vec![0, 1, 2] ; "minimum")]
+    #[test_case("Age", FilterValue::Range(None, Some(27.0)), vec![1, 3] ; "maximum")]
+    #[test_case("Age", FilterValue::Range(Some(20.0), Some(40.0)), vec![0, 1] ; "range")]
+    #[test_case("Joined", dates(Some("2020-01-31"), None), vec![1, 2, 3] ; "from date")]
+    #[test_case("Joined", dates(None, Some("2020-01-31")), vec![0, 2] ; "to date")]
+    #[test_case("Joined", dates(Some("2020-01-01"), Some("2022-01-01")), vec![1, 2] ; "dates")]
+    #[test_case("Age", FilterValue::Text("1".to_owned()), vec![0, 1, 2, 3] ; "other kind")]
+    #[test_case("Missing", FilterValue::Text("a".to_owned()), vec![0, 1, 2, 3] ; "unknown column")]
+    fn filters_match_columns(column: &'static str, value: FilterValue, expected: Vec<usize>) {
+        let filters = Filters::default().with_filter(column, value);
+
+        assert_eq!(
+            filtered_positions(&people(), &columns(), &filters, vec![0, 1, 2, 3]),
+            expected
+        );
+    }
+
+    #[test_case("", vec![3, 2, 1, 0] ; "empty search")]
+    #[test_case("  ", vec![3, 2, 1, 0] ; "blank search")]
+    #[test_case("ed", vec![3, 1] ; "search text columns")]
+    #[test_case("VIEW", vec![2] ; "search ignoring case")]
+    #[test_case("34", vec![] ; "search without text")]
+    fn filters_search_text_columns(search: &str, expected: Vec<usize>) {
+        let filters = Filters::default().with_search(search);
+
+        assert_eq!(
+            filtered_positions(&people(), &columns(), &filters, vec![3, 2, 1, 0]),
+            expected
+        );
+    }
+
+    #[test]
+    fn filters_combine_search_and_columns() {
+        let filters = Filters::default()
+            .with_search("e")
+            .with_filter("Age", FilterValue::Range(None, Some(30.0)));
+
+        assert_eq!(
+            filtered_positions(&people(), &columns(), &filters, vec![0, 1, 2, 3]),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn filters_drop_empty_values() {
+        let filters = Filters::default()
+            .with_filter("Name", FilterValue::Text("a".to_owned()))
+            .with_filter("Name", FilterValue::Text(String::new()));
+
+        assert_eq!(filters.filter("Name"), None);
+        assert!(filters.is_empty());
+        assert!(!filters.with_search("a").is_empty());
+    }
+
+    #[test]
+    fn filters_are_equal_when_sharing_extractors() {
+        let filter = Filter::range(|row: &Person| f64::from(row.2));
+
+        assert!(filter == filter.clone());
+        assert!(filter != Filter::range(|row: &Person| f64::from(row.2)));
+        assert!(Filter::text(|row: &Person| row.0.to_owned()) != filter);
+    }
+}
@@ -0,0 +1,274 @@
+use web_sys::HtmlInputElement;
+use yew::{
+    function_component, html, html::TargetCast, AttrValue, Callback, Children, Event, Html,
+    Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma checkbox element][bd].
+///
+/// Defines the properties of the checkbox element, based on the specification
+/// found in the [Bulma checkbox element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::Checkbox;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Checkbox>{"Remember me"}</Checkbox>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CheckboxProperties {
+    /// Whether or not the [Bulma checkbox element][bd] is checked.
+    ///
+    /// Whether or not the [Bulma checkbox element][bd], which will receive
+    /// these properties, is checked. Together with the `onchange` callback, it
+    /// allows the checkbox to be controlled by the state of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox checked=true>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub checked: bool,
+    /// Sets the callback to be used when the [Bulma checkbox element][bd] changes.
+    ///
+    /// Sets the callback to be used when the [Bulma checkbox element][bd],
+    /// which will receive these properties, is checked or unchecked by the
+    /// user. The callback receives whether or not the checkbox is now checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let checked = use_state(|| false);
+    ///     let onchange = {
+    ///         let checked = checked.clone();
+    ///         Callback::from(move |value: bool| checked.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Checkbox checked={*checked} {onchange}>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub onchange: Option<Callback<bool>>,
+    /// Sets the name of the [Bulma checkbox element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] of the [Bulma checkbox element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox name="remember">{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the color of the [Bulma checkbox element][bd].
+    ///
+    /// Sets the color class of the [Bulma checkbox element][bd] which will
+    /// receive these properties. Bulma itself does not style colored
+    /// checkboxes, but extensions such as `bulma-checkradio` do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::Checkbox, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox color={Color::Success}>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma checkbox element][bd].
+    ///
+    /// Sets the size class of the [Bulma checkbox element][bd] which will
+    /// receive these properties. Bulma itself does not style sized
+    /// checkboxes, but extensions such as `bulma-checkradio` do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::Checkbox, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox size={Size::Large}>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [Bulma checkbox element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma checkbox element][bd], which will receive
+    /// these properties, is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox disabled=true>{"Save my preferences"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/#disabled
+    #[prop_or_default]
+    pub disabled: bool,
+    /// The list of elements found inside the [Bulma checkbox element][bd].
+    ///
+    /// Defines the elements that will be found next to the box of the
+    /// [Bulma checkbox element][bd] which will receive these properties,
+    /// usually its label.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma checkbox element][bd].
+///
+/// Yew implementation of the checkbox element, based on the specification
+/// found in the [Bulma checkbox element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Checkbox, Control, Field};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let agreed = use_state(|| false);
+///     let onchange = {
+///         let agreed = agreed.clone();
+///         Callback::from(move |value: bool| agreed.set(value))
+///     };
+///
+///     html! {
+///         <Field>
+///             <Control>
+///                 <Checkbox checked={*agreed} {onchange}>
+///                     {" I agree to the terms and conditions"}
+///                 </Checkbox>
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+#[function_component(Checkbox)]
+pub fn checkbox(props: &CheckboxProperties) -> Html {
+    let onchange = {
+        let onchange = props.onchange.clone();
+        Callback::from(move |event: Event| {
+            if let Some(onchange) = &onchange {
+                onchange.emit(event.target_unchecked_into::<HtmlInputElement>().checked());
+            }
+        })
+    };
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("checkbox")
+        .with_color(props.color)
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <label id={&props.id} {class} disabled={props.disabled}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <input type="checkbox" name={&props.name} checked={props.checked}
+                disabled={props.disabled} {onchange} />
+            { for props.children.iter() }
+        </label>
+    }
+}
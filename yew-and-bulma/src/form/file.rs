@@ -0,0 +1,530 @@
+use web_sys::{File, FileList, HtmlInputElement};
+use yew::{
+    function_component, html, html::TargetCast, use_state, AttrValue, Callback, DragEvent, Event,
+    Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::Align,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma file element][bd].
+///
+/// Defines the properties of the file element, based on the specification
+/// found in the [Bulma file element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::file::FileInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FileInput has_name=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FileInputProperties {
+    /// Sets the label of the [Bulma file element][bd].
+    ///
+    /// Sets the label shown inside the call to action of the
+    /// [Bulma file element][bd] which will receive these properties. Defaults
+    /// to `Choose a file…`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput label="Upload a resume" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or(AttrValue::from("Choose a file\u{2026}"))]
+    pub label: AttrValue,
+    /// Sets the framework specific HTML of the [Bulma file element][bd] icon.
+    ///
+    /// Sets the framework specific HTML of the icon shown inside the call to
+    /// action of the [Bulma file element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput icon={html! { <i class="fas fa-upload"></i> }} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Sets the name of the [Bulma file element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] of the file input found inside the
+    /// [Bulma file element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput name="resume" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the accepted file types of the [Bulma file element][bd].
+    ///
+    /// Sets the [HTML accept attribute][accept] of the file input found inside
+    /// the [Bulma file element][bd] which will receive these properties.
+    /// Dropped files are checked against it as well, by extension or by MIME
+    /// type, the other ones being ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput accept="image/*" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    /// [accept]: https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/accept
+    #[prop_or_default]
+    pub accept: Option<AttrValue>,
+    /// Whether or not the [Bulma file element][bd] accepts multiple files.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, allows multiple files to be selected or dropped at once.
+    /// When it does not, only the first dropped file is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput multiple=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub multiple: bool,
+    /// Sets the callback to be used when files are chosen in the [file element][bd].
+    ///
+    /// Sets the callback to be used when files are selected or dropped onto
+    /// the [Bulma file element][bd] which will receive these properties. The
+    /// callback receives the chosen files.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let files = use_state(Vec::new);
+    ///     let onfiles = {
+    ///         let files = files.clone();
+    ///         Callback::from(move |chosen: Vec<web_sys::File>| files.set(chosen))
+    ///     };
+    ///
+    ///     html! {
+    ///         <FileInput {onfiles} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub onfiles: Option<Callback<Vec<File>>>,
+    /// Whether or not the [Bulma file element][bd] shows the file names.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, shows the names of the chosen files next to its call to
+    /// action.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput has_name=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#file-name
+    #[prop_or_default]
+    pub has_name: bool,
+    /// Whether or not the [Bulma file element][bd] is boxed.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, should be displayed as a box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput boxed=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#boxed
+    #[prop_or_default]
+    pub boxed: bool,
+    /// Whether or not the [Bulma file element][bd] takes the full width.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, should take the full width of its container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput has_name=true fullwidth=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#fullwidth
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Sets the alignment of the [Bulma file element][bd].
+    ///
+    /// Sets the alignment of the [Bulma file element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::button::Align, form::file::FileInput};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput align={Align::Center} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#alignment
+    #[prop_or_default]
+    pub align: Option<Align>,
+    /// Sets the color of the [Bulma file element][bd].
+    ///
+    /// Sets the color of the [Bulma file element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::file::FileInput, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma file element][bd].
+    ///
+    /// Sets the size of the [Bulma file element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::file::FileInput, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileInput size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+}
+
+/// Yew implementation of the [Bulma file element][bd].
+///
+/// Yew implementation of the file element, based on the specification found in
+/// the [Bulma file element documentation][bd]. Files can either be selected
+/// through the browser dialog or dropped onto the element, and their names
+/// are shown when the element `has_name`.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{form::file::FileInput, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let size = use_state(|| 0.0);
+///     let onfiles = {
+///         let size = size.clone();
+///         Callback::from(move |files: Vec<web_sys::File>| {
+///             size.set(files.iter().map(|file| file.size()).sum());
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <FileInput color={Color::Info} has_name=true boxed=true
+///                 icon={html! { <i class="fas fa-upload"></i> }} {onfiles} />
+///             <p>{format!("{} bytes", *size)}</p>
+///         </>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+#[function_component(FileInput)]
+pub fn file_input(props: &FileInputProperties) -> Html {
+    let names = use_state(Vec::<String>::new);
+    let choose = {
+        let names = names.clone();
+        let onfiles = props.onfiles.clone();
+        Callback::from(move |files: Vec<File>| {
+            names.set(files.iter().map(|file| file.name()).collect());
+            if let Some(onfiles) = &onfiles {
+                onfiles.emit(files);
+            }
+        })
+    };
+    let onchange = {
+        let choose = choose.clone();
+        Callback::from(move |event: Event| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+            choose.emit(files_from(input.files()));
+        })
+    };
+    let ondragover = {
+        let ondragover = props.ondragover.clone();
+        Callback::from(move |event: DragEvent| {
+            // Dropping is only allowed when the default behaviour is prevented.
+            event.prevent_default();
+            if let Some(ondragover) = &ondragover {
+                ondragover.emit(event);
+            }
+        })
+    };
+    let ondrop = {
+        let multiple = props.multiple;
+        let accept = props.accept.clone();
+        let ondrop = props.ondrop.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            let mut files = files_from(event.data_transfer().and_then(|data| data.files()));
+            if let Some(accept) = &accept {
+                files.retain(|file| accepts(accept, &file.name(), &file.type_()));
+            }
+            if !multiple {
+                files.truncate(1);
+            }
+            if !files.is_empty() {
+                choose.emit(files);
+            }
+            if let Some(ondrop) = &ondrop {
+                ondrop.emit(event);
+            }
+        })
+    };
+
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("file")
+        .with_color(props.color)
+        .with_custom_class(&size)
+        .with_custom_class(if props.has_name { "has-name" } else { "" })
+        .with_custom_class(if props.boxed { "is-boxed" } else { "" })
+        .with_custom_class(if props.fullwidth { "is-fullwidth" } else { "" })
+        .with_custom_class(
+            &props
+                .align
+                .as_ref()
+                .map(String::from)
+                .unwrap_or("".to_owned()),
+        )
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class} {ondragover} {ondrop}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragstart={props.ondragstart.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <label class="file-label">
+                <input class="file-input" type="file" name={&props.name}
+                    accept={&props.accept} multiple={props.multiple} {onchange} />
+                <span class="file-cta">
+                    if let Some(icon) = props.icon.clone() {
+                        <span class="file-icon">{ icon }</span>
+                    }
+                    <span class="file-label">{ &props.label }</span>
+                </span>
+                if props.has_name {
+                    <span class="file-name">{ names.join(", ") }</span>
+                }
+            </label>
+        </div>
+    }
+}
+
+/// Collects the files of the given list, if any, in order.
+fn files_from(list: Option<FileList>) -> Vec<File> {
+    list.map(|list| {
+        (0..list.length())
+            .filter_map(|index| list.get(index))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Determines if a file with the given name and MIME type matches the given
+/// [HTML accept attribute][accept].
+///
+/// The attribute is a comma separated list of extensions, such as `.pdf`,
+/// MIME types, such as `image/png`, or MIME type wildcards, such as `image/*`,
+/// all compared case insensitively. An empty attribute accepts any file.
+///
+/// [accept]: https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/accept
+fn accepts(accept: &str, name: &str, mime: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime = mime.to_lowercase();
+    let mut specifiers = accept
+        .split(',')
+        .map(|specifier| specifier.trim().to_lowercase())
+        .filter(|specifier| !specifier.is_empty())
+        .peekable();
+    if specifiers.peek().is_none() {
+        return true;
+    }
+
+    specifiers.any(|specifier| {
+        if specifier.starts_with('.') {
+            name.ends_with(&specifier)
+        } else if let Some(kind) = specifier.strip_suffix("/*") {
+            mime.strip_prefix(kind)
+                .map_or(false, |subtype| subtype.starts_with('/'))
+        } else {
+            mime == specifier
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", "notes.txt", "text/plain", true ; "empty accepts anything")]
+    #[test_case(" , ", "notes.txt", "text/plain", true ; "blank specifiers accept anything")]
+    #[test_case(".pdf", "report.pdf", "application/pdf", true ; "extension matches")]
+    #[test_case(".pdf", "REPORT.PDF", "", true ; "extension ignores case")]
+    #[test_case(".pdf", "report.pdf.txt", "text/plain", false ; "extension must end the name")]
+    #[test_case("image/*", "photo.png", "image/png", true ; "wildcard matches type")]
+    #[test_case("image/*", "photo", "imagery/png", false ; "wildcard matches whole type")]
+    #[test_case("image/*", "photo", "", false ; "wildcard needs a mime type")]
+    #[test_case("application/json", "data.json", "Application/JSON", true ; "mime type ignores case")]
+    #[test_case("application/json", "data.json", "text/json", false ; "mime type must match")]
+    #[test_case(".csv, text/plain", "notes.txt", "text/plain", true ; "any specifier matches")]
+    #[test_case(".csv, image/*", "notes.txt", "text/plain", false ; "no specifier matches")]
+    fn accepts_files(accept: &str, name: &str, mime: &str, expected: bool) {
+        assert_eq!(accepts(accept, name, mime), expected);
+    }
+}
@@ -0,0 +1,1181 @@
+use yew::{
+    classes, function_component, hook, html, use_callback, use_context, use_effect_with_deps,
+    use_state, virtual_dom::VChild, AttrValue, Callback, Children, ContextProvider, Html,
+    Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{button::Align, icon::Icon},
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Shares the validation result of a [`Field`] with the form elements found
+/// inside it.
+#[derive(Clone, PartialEq)]
+struct FieldContext {
+    validation: Option<Result<(), String>>,
+}
+
+/// Returns the color matching the validation result of the enclosing
+/// [`Field`], if any.
+///
+/// Successful validations map to [`Color::Success`] and failed ones to
+/// [`Color::Danger`]. Form elements use it when they are not given a color of
+/// their own.
+#[hook]
+pub(crate) fn use_validation_color() -> Option<Color> {
+    use_context::<FieldContext>()
+        .and_then(|context| context.validation)
+        .map(|validation| match validation {
+            Ok(()) => Color::Success,
+            Err(_) => Color::Danger,
+        })
+}
+
+/// Lets the form elements found inside a [`Control`] mark it as loading.
+#[derive(Clone, PartialEq)]
+struct ControlContext {
+    onloading: Callback<bool>,
+}
+
+/// Marks the enclosing [`Control`], if any, as loading while `loading` is set.
+///
+/// Returns whether or not there is an enclosing control. Since Bulma attaches
+/// the loading spinner to the control, form elements only render a loading
+/// control of their own when they are not already inside one.
+#[hook]
+pub(crate) fn use_control_loading(loading: bool) -> bool {
+    let context = use_context::<ControlContext>();
+    {
+        let context = context.clone();
+        use_effect_with_deps(
+            move |&loading| {
+                if let Some(context) = context.as_ref().filter(|_| loading) {
+                    context.onloading.emit(true);
+                }
+                move || {
+                    if let Some(context) = context.as_ref().filter(|_| loading) {
+                        context.onloading.emit(false);
+                    }
+                }
+            },
+            loading,
+        );
+    }
+
+    context.is_some()
+}
+
+/// Defines the properties of the [Bulma field element][bd].
+///
+/// Defines the properties of the field element, based on the specification
+/// found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldProperties {
+    /// Whether or not the [Bulma field element][bd] has addons.
+    ///
+    /// Whether or not the controls of the [Bulma field element][bd], which
+    /// will receive these properties, should be attached together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::button::Button,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field addons=true>
+    ///             <Control>
+    ///                 <input class="input" type="text" />
+    ///             </Control>
+    ///             <Control>
+    ///                 <Button>{"Search"}</Button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-addons
+    #[prop_or_default]
+    pub addons: bool,
+    /// Whether or not the [Bulma field element][bd] is grouped.
+    ///
+    /// Whether or not the controls of the [Bulma field element][bd], which
+    /// will receive these properties, should be grouped next to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::button::Button,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true>
+    ///             <Control>
+    ///                 <Button>{"Submit"}</Button>
+    ///             </Control>
+    ///             <Control>
+    ///                 <Button>{"Cancel"}</Button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub grouped: bool,
+    /// Whether or not the grouped [Bulma field element][bd] is multiline.
+    ///
+    /// Whether or not the controls of the grouped [Bulma field element][bd],
+    /// which will receive these properties, should wrap on multiple lines.
+    /// Only used when the field is `grouped`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::button::Button,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true multiline=true>
+    ///             <Control>
+    ///                 <Button>{"One"}</Button>
+    ///             </Control>
+    ///             <Control>
+    ///                 <Button>{"Two"}</Button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub multiline: bool,
+    /// Sets the alignment of the controls of the [Bulma field element][bd].
+    ///
+    /// Sets the alignment of the controls of the [Bulma field element][bd]
+    /// which will receive these properties. Only used when the field has
+    /// `addons` or is `grouped`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::button::{Align, Button},
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true align={Align::Center}>
+    ///             <Control>
+    ///                 <Button>{"Submit"}</Button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub align: Option<Align>,
+    /// Whether or not the [Bulma field element][bd] is horizontal.
+    ///
+    /// Whether or not the [Bulma field element][bd], which will receive these
+    /// properties, should place its [`FieldLabel`] next to its [`FieldBody`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{Control, Field, FieldBody, FieldLabel, Label};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field horizontal=true>
+    ///             <FieldLabel>
+    ///                 <Label>{"From"}</Label>
+    ///             </FieldLabel>
+    ///             <FieldBody>
+    ///                 <Field>
+    ///                     <Control>
+    ///                         <input class="input" type="text" />
+    ///                     </Control>
+    ///                 </Field>
+    ///             </FieldBody>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    #[prop_or_default]
+    pub horizontal: bool,
+    /// Sets the validation result of the [Bulma field element][bd].
+    ///
+    /// Sets the validation result of the [Bulma field element][bd] which will
+    /// receive these properties. The [`Input`], [`Select`], [`Textarea`] and
+    /// [`Help`] elements found inside the field, which have no color of their
+    /// own, are colored as a success or a danger accordingly, and the error
+    /// message is shown after the elements of the field. Nested fields
+    /// without a validation result share the one of their parent.
+    ///
+    /// Usually set from a [`crate::form::state::FormState`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{input::Input, Control, Field, Label};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let validation = Some(Err("This email is invalid".to_owned()));
+    ///
+    ///     html! {
+    ///         <Field {validation}>
+    ///             <Label>{"Email"}</Label>
+    ///             <Control>
+    ///                 <Input value="hello@" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    /// [`Input`]: crate::form::input::Input
+    /// [`Select`]: crate::form::select::Select
+    /// [`Textarea`]: crate::form::textarea::Textarea
+    #[prop_or_default]
+    pub validation: Option<Result<(), String>>,
+    /// The list of elements found inside the [Bulma field element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field element][bd].
+///
+/// Yew implementation of the field element, based on the specification found
+/// in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, Help, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///             <Help>{"Your full name"}</Help>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(Field)]
+pub fn field(props: &FieldProperties) -> Html {
+    let parent = use_context::<FieldContext>();
+    let context = FieldContext {
+        validation: props
+            .validation
+            .clone()
+            .or_else(|| parent.and_then(|parent| parent.validation)),
+    };
+    let align = props
+        .align
+        .as_ref()
+        .map(|align| match align {
+            Align::Left => "",
+            Align::Center => "centered",
+            Align::Right => "right",
+        })
+        .filter(|align| !align.is_empty());
+    let addons = if props.addons {
+        align
+            .map(|align| format!("has-addons has-addons-{align}"))
+            .unwrap_or("has-addons".to_owned())
+    } else {
+        "".to_owned()
+    };
+    let grouped = if props.grouped {
+        align
+            .map(|align| format!("{IS_PREFIX}-grouped {IS_PREFIX}-grouped-{align}"))
+            .unwrap_or(format!("{IS_PREFIX}-grouped"))
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("field")
+        .with_custom_class(&addons)
+        .with_custom_class(&grouped)
+        .with_custom_class(if props.grouped && props.multiline {
+            "is-grouped-multiline"
+        } else {
+            ""
+        })
+        .with_custom_class(if props.horizontal {
+            "is-horizontal"
+        } else {
+            ""
+        })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<FieldContext> {context}>
+            <div id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+                if let Some(Err(message)) = &props.validation {
+                    <p class="help is-danger">{ message }</p>
+                }
+            </div>
+        </ContextProvider<FieldContext>>
+    }
+}
+
+/// Defines the properties of the [Bulma field label element][bd].
+///
+/// Defines the properties of the label side of a horizontal field element,
+/// based on the specification found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, FieldLabel, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field horizontal=true>
+///             <FieldLabel>
+///                 <Label>{"From"}</Label>
+///             </FieldLabel>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldLabelProperties {
+    /// Sets the size of the [Bulma field label element][bd].
+    ///
+    /// Sets the size of the [Bulma field label element][bd] which will receive
+    /// these properties. It should match the size of the controls found in
+    /// the field body, so that the label is vertically aligned with them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     form::{Field, FieldLabel, Label},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field horizontal=true>
+    ///             <FieldLabel size={Size::Normal}>
+    ///                 <Label>{"From"}</Label>
+    ///             </FieldLabel>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// The list of elements found inside the [Bulma field label element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field label element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field label element][bd].
+///
+/// Yew implementation of the label side of a horizontal field element, based
+/// on the specification found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, FieldLabel, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field horizontal=true>
+///             <FieldLabel>
+///                 <Label>{"From"}</Label>
+///             </FieldLabel>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[function_component(FieldLabel)]
+pub fn field_label(props: &FieldLabelProperties) -> Html {
+    let size = props
+        .size
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("field-label")
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma field body element][bd].
+///
+/// Defines the properties of the body side of a horizontal field element,
+/// based on the specification found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, FieldBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field horizontal=true>
+///             <FieldBody>
+///                 <Field>
+///                     <Control>
+///                         <input class="input" type="text" />
+///                     </Control>
+///                 </Field>
+///             </FieldBody>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldBodyProperties {
+    /// The list of elements found inside the [Bulma field body element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field body element][bd] which will receive these properties,
+    /// usually one or more [`Field`] elements.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field body element][bd].
+///
+/// Yew implementation of the body side of a horizontal field element, based
+/// on the specification found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, FieldBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field horizontal=true>
+///             <FieldBody>
+///                 <Field>
+///                     <Control>
+///                         <input class="input" type="text" />
+///                     </Control>
+///                 </Field>
+///             </FieldBody>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[function_component(FieldBody)]
+pub fn field_body(props: &FieldBodyProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("field-body")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma label element][bd].
+///
+/// Defines the properties of the label element, based on the specification
+/// found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct LabelProperties {
+    /// Sets the ID of the form element described by the [Bulma label][bd].
+    ///
+    /// Sets the [HTML for attribute][for] of the [Bulma label element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{Control, Field, Label};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Label html_for="name">{"Name"}</Label>
+    ///             <Control>
+    ///                 <input id="name" class="input" type="text" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    /// [for]: https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/for
+    #[prop_or_default]
+    pub html_for: Option<AttrValue>,
+    /// Sets the size of the [Bulma label element][bd].
+    ///
+    /// Sets the size of the [Bulma label element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     form::{Field, Label},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Label size={Size::Large}>{"Name"}</Label>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// The list of elements found inside the [Bulma label element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma label element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma label element][bd].
+///
+/// Yew implementation of the label element, based on the specification found
+/// in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(Label)]
+pub fn label(props: &LabelProperties) -> Html {
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("label")
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <label id={&props.id} {class} for={&props.html_for}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </label>
+    }
+}
+
+/// Defines the properties of the [Bulma control element][bd].
+///
+/// Defines the properties of the control element, based on the specification
+/// found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#form-control
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ControlProperties {
+    /// Sets the icon shown on the left of the [Bulma control element][bd].
+    ///
+    /// Sets the icon shown on the left side of the form element found inside
+    /// the [Bulma control element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::icon::Icon,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Control icon_left={html_nested! {
+    ///                 <Icon icon={html! { <i class="fas fa-envelope"></i> }} />
+    ///             }}>
+    ///                 <input class="input" type="email" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#with-icons
+    #[prop_or_default]
+    pub icon_left: Option<VChild<Icon>>,
+    /// Sets the icon shown on the right of the [Bulma control element][bd].
+    ///
+    /// Sets the icon shown on the right side of the form element found inside
+    /// the [Bulma control element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::icon::Icon,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Control icon_right={html_nested! {
+    ///                 <Icon icon={html! { <i class="fas fa-check"></i> }} />
+    ///             }}>
+    ///                 <input class="input" type="email" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#with-icons
+    #[prop_or_default]
+    pub icon_right: Option<VChild<Icon>>,
+    /// Whether or not the [Bulma control element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, should display a loading spinner. The control also
+    /// displays it while a loading form element, such as an
+    /// [`crate::form::input::Input`], is found inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Control loading=true>
+    ///                 <input class="input" type="text" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-control
+    #[prop_or_default]
+    pub loading: bool,
+    /// Whether or not the [Bulma control element][bd] is expanded.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, should fill the remaining space of a grouped field
+    /// or of a field with addons.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::button::Button,
+    ///     form::{Control, Field},
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true>
+    ///             <Control expanded=true>
+    ///                 <input class="input" type="text" />
+    ///             </Control>
+    ///             <Control>
+    ///                 <Button>{"Search"}</Button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub expanded: bool,
+    /// The list of elements found inside the [Bulma control element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma control element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-control
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma control element][bd].
+///
+/// Yew implementation of the control element, based on the specification found
+/// in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     elements::icon::Icon,
+///     form::{Control, Field},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Control
+///                 icon_left={html_nested! {
+///                     <Icon icon={html! { <i class="fas fa-envelope"></i> }} />
+///                 }}
+///                 icon_right={html_nested! {
+///                     <Icon icon={html! { <i class="fas fa-check"></i> }} />
+///                 }}>
+///                 <input class="input" type="email" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#form-control
+#[function_component(Control)]
+pub fn control(props: &ControlProperties) -> Html {
+    let child_loading = use_state(|| false);
+    // The callback is built once, so that the context stays equal between
+    // renders and does not re-render the form elements inside the control.
+    let onloading = {
+        let child_loading = child_loading.setter();
+        use_callback(move |loading, _| child_loading.set(loading), ())
+    };
+    let context = ControlContext { onloading };
+    let class = ClassBuilder::default()
+        .with_custom_class("control")
+        .with_custom_class(if props.icon_left.is_some() {
+            "has-icons-left"
+        } else {
+            ""
+        })
+        .with_custom_class(if props.icon_right.is_some() {
+            "has-icons-right"
+        } else {
+            ""
+        })
+        .with_custom_class(if props.loading || *child_loading {
+            "is-loading"
+        } else {
+            ""
+        })
+        .with_custom_class(if props.expanded { "is-expanded" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<ControlContext> {context}>
+            <div id={&props.id} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+                if let Some(icon) = &props.icon_left {
+                    { side_icon(icon, "is-left") }
+                }
+                if let Some(icon) = &props.icon_right {
+                    { side_icon(icon, "is-right") }
+                }
+            </div>
+        </ContextProvider<ControlContext>>
+    }
+}
+
+/// Renders the given icon with the class placing it on one side of a control,
+/// keeping all of its other properties.
+fn side_icon(icon: &VChild<Icon>, side: &'static str) -> Html {
+    let mut props = (*icon.props).clone();
+    props.class = Some(classes!(props.class.take(), side));
+
+    html! { <Icon ..props /> }
+}
+
+/// Defines the properties of the [Bulma help element][bd].
+///
+/// Defines the properties of the help element, based on the specification
+/// found in the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, Help};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Help>{"This username is available"}</Help>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct HelpProperties {
+    /// Sets the color of the [Bulma help element][bd].
+    ///
+    /// Sets the color of the [Bulma help element][bd] which will receive these
+    /// properties, usually to mark the validation state of a field. When no
+    /// color is set, the help follows the validation result of the field
+    /// containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     form::{Field, Help},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Help color={Color::Danger}>{"This email is invalid"}</Help>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// The list of elements found inside the [Bulma help element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma help element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma help element][bd].
+///
+/// Yew implementation of the help element, based on the specification found in
+/// the [Bulma form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     form::{Control, Field, Help},
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Control>
+///                 <input class="input is-success" type="text" />
+///             </Control>
+///             <Help color={Color::Success}>{"This username is available"}</Help>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(Help)]
+pub fn help(props: &HelpProperties) -> Html {
+    let validation_color = use_validation_color();
+    let class = ClassBuilder::default()
+        .with_custom_class("help")
+        .with_color(props.color.or(validation_color))
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
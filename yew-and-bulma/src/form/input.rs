@@ -0,0 +1,493 @@
+use std::fmt::Display;
+
+use web_sys::HtmlInputElement;
+use yew::{
+    function_component, html, html::TargetCast, AttrValue, Callback, Html, InputEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::general::{use_control_loading, use_validation_color},
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the possible types of the [Bulma input element][bd].
+///
+/// Defines the possible values of the [HTML type attribute][type] of the
+/// [Bulma input element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::input::{Input, InputType};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Input input_type={InputType::Email} placeholder="Email input" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+/// [type]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputType {
+    Text,
+    Email,
+    Password,
+    Number,
+    Tel,
+    Url,
+    Search,
+    Date,
+}
+
+impl Display for InputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let input_type = match self {
+            InputType::Text => "text",
+            InputType::Email => "email",
+            InputType::Password => "password",
+            InputType::Number => "number",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+            InputType::Search => "search",
+            InputType::Date => "date",
+        };
+
+        write!(f, "{input_type}")
+    }
+}
+
+/// Defines the properties of the [Bulma input element][bd].
+///
+/// Defines the properties of the input element, based on the specification
+/// found in the [Bulma input element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::input::Input;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Input placeholder="Text input" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct InputProperties {
+    /// Sets the type of the [Bulma input element][bd].
+    ///
+    /// Sets the [HTML type attribute][type] of the [Bulma input element][bd]
+    /// which will receive these properties. Defaults to [`InputType::Text`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::{Input, InputType};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input input_type={InputType::Password} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    /// [type]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types
+    #[prop_or(InputType::Text)]
+    pub input_type: InputType,
+    /// Sets the value of the [Bulma input element][bd].
+    ///
+    /// Sets the value of the [Bulma input element][bd] which will receive these
+    /// properties. Together with the `oninput` callback, it allows the input
+    /// to be controlled by the state of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input value="bulma" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Sets the callback to be used when the [Bulma input element][bd] changes.
+    ///
+    /// Sets the callback to be used when the value of the
+    /// [Bulma input element][bd], which will receive these properties, is
+    /// changed by the user. The callback receives the new value of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let value = use_state(String::new);
+    ///     let oninput = {
+    ///         let value = value.clone();
+    ///         Callback::from(move |new_value: String| value.set(new_value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Input value={(*value).clone()} {oninput} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub oninput: Option<Callback<String>>,
+    /// Sets the placeholder of the [Bulma input element][bd].
+    ///
+    /// Sets the placeholder of the [Bulma input element][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input placeholder="Text input" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Sets the name of the [Bulma input element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] of the [Bulma input element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input name="username" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the color of the [Bulma input element][bd].
+    ///
+    /// Sets the color of the [Bulma input element][bd] which will receive these
+    /// properties. When no color is set, the input follows the validation
+    /// result of the field containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::input::Input, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input color={Color::Success} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma input element][bd].
+    ///
+    /// Sets the size of the [Bulma input element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::input::Input, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [Bulma input element][bd] is rounded.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, is rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input rounded=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Whether or not the [Bulma input element][bd] is static.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should look like plain text. Static inputs are also
+    /// read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::{Input, InputType};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input input_type={InputType::Email} value="me@example.com" is_static=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#readonly-and-static-inputs
+    #[prop_or_default]
+    pub is_static: bool,
+    /// Whether or not the [Bulma input element][bd] is read-only.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, is read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input value="bulma" readonly=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#readonly-and-static-inputs
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Whether or not the [Bulma input element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#states
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Whether or not the [Bulma input element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should display a loading spinner. Since Bulma attaches the
+    /// spinner to the control, the enclosing [`crate::form::general::Control`]
+    /// is marked as loading. Outside of a control, the input is wrapped in a
+    /// loading control of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{general::Control, input::Input};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control>
+    ///             <Input loading=true />
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#states
+    #[prop_or_default]
+    pub loading: bool,
+}
+
+/// Yew implementation of the [Bulma input element][bd].
+///
+/// Yew implementation of the input element, based on the specification found
+/// in the [Bulma input element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     form::{
+///         input::{Input, InputType},
+///         Control, Field, Label,
+///     },
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let email = use_state(String::new);
+///     let oninput = {
+///         let email = email.clone();
+///         Callback::from(move |value: String| email.set(value))
+///     };
+///
+///     html! {
+///         <Field>
+///             <Label>{"Email"}</Label>
+///             <Control>
+///                 <Input input_type={InputType::Email} color={Color::Info}
+///                     value={(*email).clone()} {oninput} />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[function_component(Input)]
+pub fn input(props: &InputProperties) -> Html {
+    let in_control = use_control_loading(props.loading);
+    let oninput = {
+        let oninput = props.oninput.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(oninput) = &oninput {
+                oninput.emit(event.target_unchecked_into::<HtmlInputElement>().value());
+            }
+        })
+    };
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let validation_color = use_validation_color();
+    let class = ClassBuilder::default()
+        .with_custom_class("input")
+        .with_color(props.color.or(validation_color))
+        .with_custom_class(&size)
+        .with_custom_class(if props.rounded { "is-rounded" } else { "" })
+        .with_custom_class(if props.is_static { "is-static" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    let input = html! {
+        <input id={&props.id} {class} type={props.input_type.to_string()}
+            value={&props.value} placeholder={&props.placeholder} name={&props.name}
+            readonly={props.readonly || props.is_static} disabled={props.disabled} {oninput}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    };
+
+    if props.loading && !in_control {
+        html! {
+            <div class="control is-loading">
+                { input }
+            </div>
+        }
+    } else {
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(InputType::Text, "text" ; "text converts to text")]
+    #[test_case(InputType::Email, "email" ; "email converts to email")]
+    #[test_case(InputType::Password, "password" ; "password converts to password")]
+    #[test_case(InputType::Number, "number" ; "number converts to number")]
+    #[test_case(InputType::Tel, "tel" ; "tel converts to tel")]
+    #[test_case(InputType::Url, "url" ; "url converts to url")]
+    #[test_case(InputType::Search, "search" ; "search converts to search")]
+    #[test_case(InputType::Date, "date" ; "date converts to date")]
+    fn input_type_values_to_string(input_type: InputType, expected_input_type: &str) {
+        let converted_input_type = format!("{input_type}");
+
+        assert_eq!(converted_input_type, expected_input_type);
+    }
+}
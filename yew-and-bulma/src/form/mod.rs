@@ -0,0 +1,286 @@
+/// Provides utilities for creating [checkbox elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma checkbox elements][bd] in Yew. These components are also
+/// available directly from the [`crate::form`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::checkbox::Checkbox;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Checkbox>{"Remember me"}</Checkbox>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+pub mod checkbox;
+/// Provides utilities for creating [file elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma file elements][bd] in Yew, which accept both selected and dropped
+/// files.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::file::FileInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FileInput has_name=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+pub mod file;
+/// Provides utilities for creating [general form elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify the
+/// [Bulma field, control, label and help elements][bd] in Yew, which wrap and
+/// describe all of the other form controls. These components are also
+/// available directly from the [`crate::form`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, Help, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///             <Help>{"Your full name"}</Help>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+pub mod general;
+/// Provides utilities for creating [input elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma input elements][bd] in Yew, whose value is passed directly to the
+/// `oninput` callback.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::input::Input;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Input placeholder="Text input" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+pub mod input;
+/// Provides utilities for creating [radio elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma radio elements][bd] in Yew, grouped by a shared selected value. These components are also
+/// available directly from the [`crate::form`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::radio::RadioGroup;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+///
+///     html! {
+///         <RadioGroup<bool> name="answer" {options} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/radio/
+pub mod radio;
+/// Provides utilities for creating [select elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma select elements][bd] in Yew, whose options hold Rust values.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::select::Select;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+///
+///     html! {
+///         <Select<u32> {options} value={1} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/select/
+pub mod select;
+/// Provides utilities for holding and validating the state of forms in Yew.
+///
+/// Defines the [`state::FormState`] type, which holds the values, the touched
+/// and dirty flags and the validators of a form, and the [`state::use_form`]
+/// hook, which keeps it in the state of a component. The validation results
+/// can be passed to the [`Field`] elements of the form, which color their
+/// elements and show the error messages.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{
+///     input::Input,
+///     state::{use_form, FormState},
+///     Control, Field,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let form = use_form(|| {
+///         FormState::new(String::new()).with_validator("name", |name| {
+///             if name.is_empty() {
+///                 Err("The name is required".to_owned())
+///             } else {
+///                 Ok(())
+///             }
+///         })
+///     });
+///     let oninput = form.callback("name", |name: &mut String, value| *name = value);
+///
+///     html! {
+///         <Field validation={form.validation("name")}>
+///             <Control>
+///                 <Input value={form.values().clone()} {oninput} />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+pub mod state;
+/// Provides utilities for creating [textarea elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma textarea elements][bd] in Yew. These components are also
+/// available directly from the [`crate::form`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::textarea::Textarea;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Textarea placeholder="e.g. Hello world" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+pub mod textarea;
+
+pub use checkbox::Checkbox;
+pub use general::{Control, Field, FieldBody, FieldLabel, Help, Label};
+pub use radio::RadioGroup;
+pub use state::{use_form, FormState};
+pub use textarea::Textarea;
+/// Derives a form component editing a struct.
+///
+/// Generates a `{Struct}Form` component, along with its
+/// `{Struct}FormProperties`, which renders a [`Field`] for each field of the
+/// struct and passes the filled struct to its `onsubmit` callback once it is
+/// submitted and valid. The form is backed by a [`state::FormState`], so
+/// invalid fields are colored and show their error messages.
+///
+/// See [the macro documentation][macro] for all of the supported
+/// `#[bulma_form(...)]` attributes.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::BulmaForm;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Role {
+///     Viewer,
+///     Editor,
+/// }
+///
+/// impl Default for Role {
+///     fn default() -> Self {
+///         Role::Viewer
+///     }
+/// }
+///
+/// impl Role {
+///     fn options() -> Vec<(Role, AttrValue)> {
+///         vec![
+///             (Role::Viewer, AttrValue::from("Viewer")),
+///             (Role::Editor, AttrValue::from("Editor")),
+///         ]
+///     }
+/// }
+///
+/// fn adult(age: &u32) -> Result<(), String> {
+///     if *age < 18 {
+///         Err("Users need to be adults".to_owned())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Clone, Default, PartialEq, BulmaForm)]
+/// #[bulma_form(submit = "Save")]
+/// struct User {
+///     #[bulma_form(required, placeholder = "Jane Doe")]
+///     name: String,
+///     #[bulma_form(input_type = "Email", help = "Used to log in")]
+///     email: String,
+///     #[bulma_form(validate = "adult")]
+///     age: u32,
+///     #[bulma_form(options = "Role::options")]
+///     role: Role,
+///     #[bulma_form(label = "Active account")]
+///     active: bool,
+///     #[bulma_form(skip)]
+///     id: u64,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsubmit = Callback::from(|_user: User| {});
+///
+///     html! {
+///         <UserForm {onsubmit} />
+///     }
+/// }
+/// ```
+///
+/// [macro]: yew_and_bulma_macros::BulmaForm
+pub use yew_and_bulma_macros::BulmaForm;
@@ -0,0 +1,334 @@
+use yew::{function_component, html, AttrValue, Callback, Html, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma radio group element][bd].
+///
+/// Defines the properties of a group of radio elements sharing one selected
+/// value, based on the specification found in the
+/// [Bulma radio element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::RadioGroup;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+///
+///     html! {
+///         <RadioGroup<bool> name="answer" {options} value={true} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/radio/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct RadioGroupProperties<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Sets the name of the [Bulma radio group element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] shared by all of the radio buttons
+    /// of the [Bulma radio group element][bd] which will receive these
+    /// properties. It should be unique inside the form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::RadioGroup;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/radio#defining_a_radio_group
+    pub name: AttrValue,
+    /// Sets the radio buttons of the [Bulma radio group element][bd].
+    ///
+    /// Sets the radio buttons of the [Bulma radio group element][bd] which
+    /// will receive these properties, as a list of values and their labels,
+    /// in the order in which they are shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::RadioGroup;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![
+    ///         ('s', AttrValue::from("Small")),
+    ///         ('m', AttrValue::from("Medium")),
+    ///         ('l', AttrValue::from("Large")),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <RadioGroup<char> name="size" {options} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    pub options: Vec<(T, AttrValue)>,
+    /// Sets the selected value of the [Bulma radio group element][bd].
+    ///
+    /// Sets the value of the checked radio button of the
+    /// [Bulma radio group element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::RadioGroup;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} value={false} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    #[prop_or_default]
+    pub value: Option<T>,
+    /// Sets the callback to be used when the [Bulma radio group][bd] changes.
+    ///
+    /// Sets the callback to be used when a radio button of the
+    /// [Bulma radio group element][bd], which will receive these properties,
+    /// is checked by the user. The callback receives the value of the checked
+    /// radio button.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::RadioGroup;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///     let answer = use_state(|| None);
+    ///     let onchange = {
+    ///         let answer = answer.clone();
+    ///         Callback::from(move |value: bool| answer.set(Some(value)))
+    ///     };
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} value={*answer} {onchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    #[prop_or_default]
+    pub onchange: Option<Callback<T>>,
+    /// Sets the color of the [Bulma radio group element][bd].
+    ///
+    /// Sets the color class of the radio buttons of the
+    /// [Bulma radio group element][bd] which will receive these properties.
+    /// Bulma itself does not style colored radio buttons, but extensions such
+    /// as `bulma-checkradio` do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::RadioGroup, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} color={Color::Info} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma radio group element][bd].
+    ///
+    /// Sets the size class of the radio buttons of the
+    /// [Bulma radio group element][bd] which will receive these properties.
+    /// Bulma itself does not style sized radio buttons, but extensions such
+    /// as `bulma-checkradio` do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::RadioGroup, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [Bulma radio group element][bd] is disabled.
+    ///
+    /// Whether or not all of the radio buttons of the
+    /// [Bulma radio group element][bd], which will receive these properties,
+    /// are disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::RadioGroup;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(true, AttrValue::from("Yes")), (false, AttrValue::from("No"))];
+    ///
+    ///     html! {
+    ///         <RadioGroup<bool> name="answer" {options} disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/radio/#disabled
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Yew implementation of the [Bulma radio group element][bd].
+///
+/// Yew implementation of a group of radio elements sharing one selected value,
+/// based on the specification found in the
+/// [Bulma radio element documentation][bd]. The radio buttons are rendered
+/// inside a control, so the group should be placed directly inside a field.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Field, Label, RadioGroup};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Answer {
+///     Yes,
+///     No,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![
+///         (Answer::Yes, AttrValue::from("Yes")),
+///         (Answer::No, AttrValue::from("No")),
+///     ];
+///     let answer = use_state(|| Answer::Yes);
+///     let onchange = {
+///         let answer = answer.clone();
+///         Callback::from(move |value: Answer| answer.set(value))
+///     };
+///
+///     html! {
+///         <Field>
+///             <Label>{"Do you agree?"}</Label>
+///             <RadioGroup<Answer> name="answer" {options} value={*answer} {onchange} />
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/radio/
+#[function_component(RadioGroup)]
+pub fn radio_group<T>(props: &RadioGroupProperties<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let radio_class = ClassBuilder::default()
+        .with_custom_class("radio")
+        .with_color(props.color)
+        .with_custom_class(&size)
+        .build();
+    let radios = props.options.iter().map(|(value, label)| {
+        let onchange = {
+            let value = value.clone();
+            let onchange = props.onchange.clone();
+            Callback::from(move |_| {
+                if let Some(onchange) = &onchange {
+                    onchange.emit(value.clone());
+                }
+            })
+        };
+
+        html! {
+            <label class={radio_class.clone()} disabled={props.disabled}>
+                <input type="radio" name={&props.name}
+                    checked={props.value.as_ref() == Some(value)}
+                    disabled={props.disabled} {onchange} />
+                {" "}{ label }
+            </label>
+        }
+    });
+    let class = ClassBuilder::default()
+        .with_custom_class("control")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for radios }
+        </div>
+    }
+}
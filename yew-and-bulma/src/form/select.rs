@@ -0,0 +1,595 @@
+use web_sys::HtmlSelectElement;
+use yew::{
+    function_component, html, html::TargetCast, AttrValue, Callback, Event, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::general::use_validation_color,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma select element][bd].
+///
+/// Defines the properties of the select element, based on the specification
+/// found in the [Bulma select element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::select::Select;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+///
+///     html! {
+///         <Select<u32> {options} value={1} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/select/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct SelectProperties<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Sets the options of the [Bulma select element][bd].
+    ///
+    /// Sets the options of the [Bulma select element][bd] which will receive
+    /// these properties, as a list of values and their labels, in the order
+    /// in which they are shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![
+    ///         ("ro", AttrValue::from("Romania")),
+    ///         ("fr", AttrValue::from("France")),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <Select<&'static str> {options} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    pub options: Vec<(T, AttrValue)>,
+    /// Sets the selected value of the [Bulma select element][bd].
+    ///
+    /// Sets the value of the selected option of the [Bulma select element][bd]
+    /// which will receive these properties. Only used when the select is not
+    /// `multiple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} value={2} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    #[prop_or_default]
+    pub value: Option<T>,
+    /// Sets the selected values of the multiple [Bulma select element][bd].
+    ///
+    /// Sets the values of the selected options of the [Bulma select element][bd]
+    /// which will receive these properties. Only used when the select is
+    /// `multiple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![
+    ///         (1, AttrValue::from("One")),
+    ///         (2, AttrValue::from("Two")),
+    ///         (3, AttrValue::from("Three")),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} multiple=true values={vec![1, 3]} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#multiple-select
+    #[prop_or_default]
+    pub values: Vec<T>,
+    /// Sets the placeholder of the [Bulma select element][bd].
+    ///
+    /// Sets the label of a disabled option, shown first by the
+    /// [Bulma select element][bd] which will receive these properties and
+    /// selected while no `value` is set. Only used when the select is not
+    /// `multiple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} placeholder="Pick a number" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Sets the callback to be used when the [Bulma select element][bd] changes.
+    ///
+    /// Sets the callback to be used when an option of the
+    /// [Bulma select element][bd], which will receive these properties, is
+    /// selected. The callback receives the value of the selected option. Only
+    /// used when the select is not `multiple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///     let value = use_state(|| 1);
+    ///     let onchange = {
+    ///         let value = value.clone();
+    ///         Callback::from(move |selected: u32| value.set(selected))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} value={*value} {onchange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    #[prop_or_default]
+    pub onchange: Option<Callback<T>>,
+    /// Sets the callback to be used when the multiple [Bulma select][bd] changes.
+    ///
+    /// Sets the callback to be used when the selected options of the
+    /// [Bulma select element][bd], which will receive these properties,
+    /// change. The callback receives the values of all the selected options,
+    /// in the order in which they are shown. Only used when the select is
+    /// `multiple`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///     let values = use_state(Vec::new);
+    ///     let onchangemultiple = {
+    ///         let values = values.clone();
+    ///         Callback::from(move |selected: Vec<u32>| values.set(selected))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} multiple=true values={(*values).clone()} {onchangemultiple} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#multiple-select
+    #[prop_or_default]
+    pub onchangemultiple: Option<Callback<Vec<T>>>,
+    /// Whether or not the [Bulma select element][bd] allows multiple options.
+    ///
+    /// Whether or not the [Bulma select element][bd], which will receive these
+    /// properties, allows multiple options to be selected at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} multiple=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#multiple-select
+    #[prop_or_default]
+    pub multiple: bool,
+    /// Sets the name of the [Bulma select element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] of the [Bulma select element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} name="number" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/select#attr-name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the color of the [Bulma select element][bd].
+    ///
+    /// Sets the color of the [Bulma select element][bd] which will receive
+    /// these properties. When no color is set, the select follows the
+    /// validation result of the field containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::select::Select, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma select element][bd].
+    ///
+    /// Sets the size of the [Bulma select element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::select::Select, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} size={Size::Small} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [Bulma select element][bd] is rounded.
+    ///
+    /// Whether or not the [Bulma select element][bd], which will receive these
+    /// properties, is rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} rounded=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Whether or not the [Bulma select element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma select element][bd], which will receive these
+    /// properties, should display a loading spinner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} loading=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#states
+    #[prop_or_default]
+    pub loading: bool,
+    /// Whether or not the [Bulma select element][bd] takes the full width.
+    ///
+    /// Whether or not the [Bulma select element][bd], which will receive these
+    /// properties, should take the full width of its container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} fullwidth=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Whether or not the [Bulma select element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma select element][bd], which will receive these
+    /// properties, is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::select::Select;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let options = vec![(1, AttrValue::from("One")), (2, AttrValue::from("Two"))];
+    ///
+    ///     html! {
+    ///         <Select<u32> {options} disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/select/#states
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Yew implementation of the [Bulma select element][bd].
+///
+/// Yew implementation of the select element, based on the specification found
+/// in the [Bulma select element documentation][bd]. The options are rendered
+/// with their position as their DOM value, which is mapped back to the
+/// matching Rust value before being passed to the callbacks.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{select::Select, Control, Field, Label};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Plan {
+///     Free,
+///     Pro,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let options = vec![
+///         (Plan::Free, AttrValue::from("Free")),
+///         (Plan::Pro, AttrValue::from("Pro")),
+///     ];
+///     let plan = use_state(|| Plan::Free);
+///     let onchange = {
+///         let plan = plan.clone();
+///         Callback::from(move |selected: Plan| plan.set(selected))
+///     };
+///
+///     html! {
+///         <Field>
+///             <Label>{"Plan"}</Label>
+///             <Control>
+///                 <Select<Plan> {options} value={*plan} {onchange} />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/select/
+#[function_component(Select)]
+pub fn select<T>(props: &SelectProperties<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let onchange = {
+        let options = props.options.clone();
+        let multiple = props.multiple;
+        let onchange = props.onchange.clone();
+        let onchangemultiple = props.onchangemultiple.clone();
+        Callback::from(move |event: Event| {
+            let select = event.target_unchecked_into::<HtmlSelectElement>();
+            if multiple {
+                let selected = select.selected_options();
+                let positions = (0..selected.length())
+                    .filter_map(|index| selected.item(index))
+                    .filter_map(|option| option.get_attribute("value"))
+                    .collect::<Vec<_>>();
+                if let Some(onchangemultiple) = &onchangemultiple {
+                    onchangemultiple.emit(selected_values(&options, &positions));
+                }
+            } else if let Some(value) = selected_values(&options, &[select.value()])
+                .into_iter()
+                .next()
+            {
+                if let Some(onchange) = &onchange {
+                    onchange.emit(value);
+                }
+            }
+        })
+    };
+    let is_selected = |value: &T| {
+        if props.multiple {
+            props.values.contains(value)
+        } else {
+            props.value.as_ref() == Some(value)
+        }
+    };
+    let options = props
+        .options
+        .iter()
+        .enumerate()
+        .map(|(position, (value, label))| {
+            html! {
+                <option value={position.to_string()} selected={is_selected(value)}>
+                    { label }
+                </option>
+            }
+        });
+
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let validation_color = use_validation_color();
+    let class = ClassBuilder::default()
+        .with_custom_class("select")
+        .with_color(props.color.or(validation_color))
+        .with_custom_class(&size)
+        .with_custom_class(if props.multiple { "is-multiple" } else { "" })
+        .with_custom_class(if props.rounded { "is-rounded" } else { "" })
+        .with_custom_class(if props.loading { "is-loading" } else { "" })
+        .with_custom_class(if props.fullwidth { "is-fullwidth" } else { "" })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <select name={&props.name} multiple={props.multiple} disabled={props.disabled} {onchange}>
+                if let Some(placeholder) = props.placeholder.as_ref().filter(|_| !props.multiple) {
+                    <option value="" disabled=true selected={props.value.is_none()}>
+                        { placeholder }
+                    </option>
+                }
+                { for options }
+            </select>
+        </div>
+    }
+}
+
+/// Maps the DOM values of the selected options back to their Rust values.
+///
+/// Since the options are rendered with their position as their DOM value, each
+/// position is parsed and looked up in the given options, in order. Positions
+/// which can not be parsed or are out of bounds, such as the one of the
+/// placeholder, are skipped.
+fn selected_values<T>(options: &[(T, AttrValue)], positions: &[String]) -> Vec<T>
+where
+    T: Clone,
+{
+    positions
+        .iter()
+        .filter_map(|position| position.parse::<usize>().ok())
+        .filter_map(|position| options.get(position))
+        .map(|(value, _)| value.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![], vec![] ; "no positions result in no values")]
+    #[test_case(vec!["0"], vec!['a'] ; "first position maps to first value")]
+    #[test_case(vec!["2", "0"], vec!['c', 'a'] ; "positions keep their order")]
+    #[test_case(vec![""], vec![] ; "placeholder position is skipped")]
+    #[test_case(vec!["3"], vec![] ; "out of bounds position is skipped")]
+    #[test_case(vec!["one", "1"], vec!['b'] ; "invalid position is skipped")]
+    fn selected_values_maps_positions_to_values(positions: Vec<&str>, expected_values: Vec<char>) {
+        let options = vec![
+            ('a', AttrValue::from("A")),
+            ('b', AttrValue::from("B")),
+            ('c', AttrValue::from("C")),
+        ];
+        let positions = positions.into_iter().map(String::from).collect::<Vec<_>>();
+
+        let values = selected_values(&options, &positions);
+
+        assert_eq!(values, expected_values);
+    }
+}
@@ -0,0 +1,644 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    rc::Rc,
+};
+
+use yew::{hook, use_reducer, Callback, FocusEvent, Reducible, SubmitEvent, UseReducerHandle};
+
+/// Defines a validator of a single field of a [`FormState`].
+///
+/// A validator receives all of the values of the form, so that fields can be
+/// validated against each other, and returns the error message to be shown
+/// when the field is invalid.
+pub type Validator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+/// Defines the parsing of the text of a field into the values of a
+/// [`FormState`], returning the error message to be shown when the text
+/// cannot be parsed.
+type Parser<T> = Box<dyn FnOnce(&mut T, &str) -> Result<(), String>>;
+
+/// Holds the values, the touched and dirty flags and the validators of a form.
+///
+/// Holds the values of a form as a single value of type `T`, usually a struct
+/// with one member for each field, and keeps track of the fields which have
+/// been touched or modified by the user. Fields are identified by name and
+/// validated by the validators registered for them, in the order in which
+/// they were registered.
+///
+/// The validation result of a field is only reported once the field has been
+/// touched or modified, or once the form has been submitted, so that errors
+/// are not shown for fields the user has not reached yet. It can be passed
+/// directly to the `validation` property of a [`Field`], which colors the
+/// elements inside it and shows the error message.
+///
+/// Fields edited as text but holding another type, such as numbers, keep the
+/// text as typed by the user. When it cannot be parsed, the values of the
+/// form are left untouched and the field is invalid until it can.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::form::state::FormState;
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Login {
+///     email: String,
+/// }
+///
+/// let mut state = FormState::new(Login::default()).with_validator("email", |login| {
+///     if login.email.contains('@') {
+///         Ok(())
+///     } else {
+///         Err("This email is invalid".to_owned())
+///     }
+/// });
+/// assert_eq!(state.validation("email"), None);
+///
+/// state.update("email", |login| login.email = "hello".to_owned());
+/// assert_eq!(
+///     state.validation("email"),
+///     Some(Err("This email is invalid".to_owned()))
+/// );
+/// ```
+///
+/// [`Field`]: crate::form::general::Field
+pub struct FormState<T> {
+    initial: T,
+    values: T,
+    touched: HashSet<&'static str>,
+    dirty: HashSet<&'static str>,
+    submitted: bool,
+    validators: Vec<(&'static str, Validator<T>)>,
+    texts: HashMap<&'static str, String>,
+    parse_errors: HashMap<&'static str, String>,
+}
+
+impl<T: Clone + PartialEq> FormState<T> {
+    /// Creates a form state holding the given initial values.
+    ///
+    /// The initial values are kept aside, so that the form can be reset and
+    /// tell whether or not it has been modified.
+    pub fn new(initial: T) -> Self {
+        Self {
+            values: initial.clone(),
+            initial,
+            touched: HashSet::new(),
+            dirty: HashSet::new(),
+            submitted: false,
+            validators: Vec::new(),
+            texts: HashMap::new(),
+            parse_errors: HashMap::new(),
+        }
+    }
+
+    /// Registers a validator for the given field.
+    ///
+    /// Several validators can be registered for the same field, in which case
+    /// the error of the first failing one is reported.
+    pub fn with_validator<F>(mut self, field: &'static str, validator: F) -> Self
+    where
+        F: Fn(&T) -> Result<(), String> + 'static,
+    {
+        self.validators.push((field, Rc::new(validator)));
+        self
+    }
+
+    /// Returns the current values of the form.
+    pub fn values(&self) -> &T {
+        &self.values
+    }
+
+    /// Updates the values of the form, marking the given field as dirty.
+    pub fn update<F: FnOnce(&mut T)>(&mut self, field: &'static str, update: F) {
+        update(&mut self.values);
+        self.dirty.insert(field);
+    }
+
+    /// Stores the text typed in the given field, parsing it into the values of
+    /// the form and marking the field as dirty.
+    ///
+    /// The text is kept as typed, so that it can be shown back as is. When the
+    /// given parser fails, the values are left untouched and the field
+    /// reports the error of the parser until its text can be parsed.
+    pub fn update_text<F>(&mut self, field: &'static str, text: String, parse: F)
+    where
+        F: FnOnce(&mut T, &str) -> Result<(), String>,
+    {
+        match parse(&mut self.values, &text) {
+            Ok(()) => self.parse_errors.remove(field),
+            Err(error) => self.parse_errors.insert(field, error),
+        };
+        self.texts.insert(field, text);
+        self.dirty.insert(field);
+    }
+
+    /// Returns the text typed in the given field, if it is edited as text and
+    /// has been modified.
+    pub fn text(&self, field: &str) -> Option<&str> {
+        self.texts.get(field).map(String::as_str)
+    }
+
+    /// Marks the given field as touched, usually once it loses focus.
+    pub fn touch(&mut self, field: &'static str) {
+        self.touched.insert(field);
+    }
+
+    /// Marks the form as submitted, which reports the validation results of
+    /// all of its fields.
+    pub fn submit(&mut self) {
+        self.submitted = true;
+    }
+
+    /// Restores the initial values of the form and clears all of its flags.
+    pub fn reset(&mut self) {
+        self.values = self.initial.clone();
+        self.touched.clear();
+        self.dirty.clear();
+        self.submitted = false;
+        self.texts.clear();
+        self.parse_errors.clear();
+    }
+
+    /// Validates the given field against the current values of the form.
+    ///
+    /// Fields whose text cannot be parsed report the error of their parser.
+    /// Other fields without validators are always valid.
+    pub fn validate(&self, field: &str) -> Result<(), String> {
+        if let Some(error) = self.parse_errors.get(field) {
+            return Err(error.clone());
+        }
+
+        self.validators
+            .iter()
+            .filter(|(name, _)| *name == field)
+            .try_for_each(|(_, validator)| validator(&self.values))
+    }
+
+    /// Returns the validation result of the given field, if it should be
+    /// reported.
+    ///
+    /// The result is only reported once the field has been touched or
+    /// modified, or once the form has been submitted.
+    pub fn validation(&self, field: &str) -> Option<Result<(), String>> {
+        if self.submitted || self.is_touched(field) || self.is_field_dirty(field) {
+            Some(self.validate(field))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the error messages of all of the invalid fields, by field name.
+    pub fn errors(&self) -> Vec<(&'static str, String)> {
+        let mut fields: Vec<&'static str> = Vec::new();
+        let mut parse_errors: Vec<&'static str> = self.parse_errors.keys().copied().collect();
+        parse_errors.sort_unstable();
+        for field in self
+            .validators
+            .iter()
+            .map(|(field, _)| *field)
+            .chain(parse_errors)
+        {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+
+        fields
+            .into_iter()
+            .filter_map(|field| self.validate(field).err().map(|error| (field, error)))
+            .collect()
+    }
+
+    /// Whether or not all of the fields of the form are valid.
+    pub fn is_valid(&self) -> bool {
+        self.parse_errors.is_empty()
+            && self
+                .validators
+                .iter()
+                .all(|(_, validator)| validator(&self.values).is_ok())
+    }
+
+    /// Whether or not the given field has been touched.
+    pub fn is_touched(&self, field: &str) -> bool {
+        self.touched.contains(field)
+    }
+
+    /// Whether or not the given field has been modified.
+    pub fn is_field_dirty(&self, field: &str) -> bool {
+        self.dirty.contains(field)
+    }
+
+    /// Whether or not the values of the form differ from the initial ones.
+    pub fn is_dirty(&self) -> bool {
+        self.values != self.initial
+    }
+
+    /// Whether or not the form has been submitted since it was last reset.
+    pub fn is_submitted(&self) -> bool {
+        self.submitted
+    }
+}
+
+impl<T: Clone> Clone for FormState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            initial: self.initial.clone(),
+            values: self.values.clone(),
+            touched: self.touched.clone(),
+            dirty: self.dirty.clone(),
+            submitted: self.submitted,
+            validators: self.validators.clone(),
+            texts: self.texts.clone(),
+            parse_errors: self.parse_errors.clone(),
+        }
+    }
+}
+
+/// Defines the changes which can be applied to a [`FormState`] held by the
+/// [`use_form`] hook.
+pub enum FormAction<T> {
+    /// Updates the values of the form, marking the given field as dirty.
+    Update(&'static str, Box<dyn FnOnce(&mut T)>),
+    /// Stores and parses the text typed in the given field.
+    UpdateText(&'static str, String, Parser<T>),
+    /// Marks the given field as touched.
+    Touch(&'static str),
+    /// Marks the form as submitted.
+    Submit,
+    /// Restores the initial values of the form.
+    Reset,
+}
+
+impl<T: Clone + PartialEq> Reducible for FormState<T> {
+    type Action = FormAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            FormAction::Update(field, update) => state.update(field, update),
+            FormAction::UpdateText(field, text, parse) => state.update_text(field, text, parse),
+            FormAction::Touch(field) => state.touch(field),
+            FormAction::Submit => state.submit(),
+            FormAction::Reset => state.reset(),
+        }
+
+        state.into()
+    }
+}
+
+/// Handle to the [`FormState`] held by the [`use_form`] hook.
+///
+/// Dereferences to the current [`FormState`], and provides the callbacks
+/// needed to update it from the form elements.
+pub struct UseFormHandle<T: Clone + PartialEq + 'static> {
+    inner: UseReducerHandle<FormState<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> UseFormHandle<T> {
+    /// Updates the values of the form, marking the given field as dirty.
+    pub fn update<F: FnOnce(&mut T) + 'static>(&self, field: &'static str, update: F) {
+        self.inner
+            .dispatch(FormAction::Update(field, Box::new(update)));
+    }
+
+    /// Marks the given field as touched.
+    pub fn touch(&self, field: &'static str) {
+        self.inner.dispatch(FormAction::Touch(field));
+    }
+
+    /// Restores the initial values of the form and clears all of its flags.
+    pub fn reset(&self) {
+        self.inner.dispatch(FormAction::Reset);
+    }
+
+    /// Creates a callback which stores the received value in the given field.
+    ///
+    /// Meant to be passed to the `oninput` or `onchange` property of the form
+    /// element editing the field.
+    pub fn callback<V, F>(&self, field: &'static str, update: F) -> Callback<V>
+    where
+        V: 'static,
+        F: Fn(&mut T, V) + 'static,
+    {
+        let inner = self.inner.clone();
+        let update = Rc::new(update);
+        Callback::from(move |value: V| {
+            let update = update.clone();
+            inner.dispatch(FormAction::Update(
+                field,
+                Box::new(move |values: &mut T| update(values, value)),
+            ));
+        })
+    }
+
+    /// Creates a callback which stores the received text in the given field,
+    /// parsing it into the values of the form.
+    ///
+    /// Meant to be passed to the `oninput` property of the input editing a
+    /// field which is not a string, such as a number. The text is kept as
+    /// typed, and the error returned by the parser is reported for the field
+    /// until its text can be parsed.
+    pub fn text_callback<F>(&self, field: &'static str, parse: F) -> Callback<String>
+    where
+        F: Fn(&mut T, &str) -> Result<(), String> + 'static,
+    {
+        let inner = self.inner.clone();
+        let parse = Rc::new(parse);
+        Callback::from(move |text: String| {
+            let parse = parse.clone();
+            inner.dispatch(FormAction::UpdateText(
+                field,
+                text,
+                Box::new(move |values: &mut T, text: &str| parse(values, text)),
+            ));
+        })
+    }
+
+    /// Creates a callback which marks the given field as touched.
+    ///
+    /// Meant to be passed to the `onblur` property of the form element editing
+    /// the field.
+    pub fn onblur(&self, field: &'static str) -> Callback<FocusEvent> {
+        let inner = self.inner.clone();
+        Callback::from(move |_| inner.dispatch(FormAction::Touch(field)))
+    }
+
+    /// Creates a callback which submits the form.
+    ///
+    /// Meant to be passed to the `onsubmit` property of the `form` element.
+    /// The default submission of the browser is prevented, the validation
+    /// results of all of the fields are reported and, if the form is valid,
+    /// its values are passed to the given callback.
+    pub fn onsubmit(&self, onsubmit: Callback<T>) -> Callback<SubmitEvent> {
+        let inner = self.inner.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            inner.dispatch(FormAction::Submit);
+            if inner.is_valid() {
+                onsubmit.emit(inner.values().clone());
+            }
+        })
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Deref for UseFormHandle<T> {
+    type Target = FormState<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Clone for UseFormHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> PartialEq for UseFormHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq::<FormState<T>>(&*self.inner, &*other.inner)
+    }
+}
+
+/// Holds the state of a form, re-rendering the component when it changes.
+///
+/// Creates the [`FormState`] of a form using the given function on the first
+/// render, and returns a [`UseFormHandle`] used to read and update it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     elements::button::Button,
+///     form::{input::{Input, InputType}, state::{use_form, FormState}, Control, Field, Label},
+/// };
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Login {
+///     email: String,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let form = use_form(|| {
+///         FormState::new(Login::default()).with_validator("email", |login| {
+///             if login.email.contains('@') {
+///                 Ok(())
+///             } else {
+///                 Err("This email is invalid".to_owned())
+///             }
+///         })
+///     });
+///     let oninput = form.callback("email", |login: &mut Login, email| login.email = email);
+///     let onsubmit = form.onsubmit(Callback::from(|_login: Login| {}));
+///
+///     html! {
+///         <form {onsubmit}>
+///             <Field validation={form.validation("email")}>
+///                 <Label>{"Email"}</Label>
+///                 <Control>
+///                     <Input input_type={InputType::Email} value={form.values().email.clone()}
+///                         {oninput} onblur={form.onblur("email")} />
+///                 </Control>
+///             </Field>
+///             <Button>{"Log in"}</Button>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_form<T, F>(init: F) -> UseFormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> FormState<T>,
+{
+    UseFormHandle {
+        inner: use_reducer(init),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Login {
+        email: String,
+        password: String,
+    }
+
+    fn login_form() -> FormState<Login> {
+        FormState::new(Login::default())
+            .with_validator("email", |login| {
+                if login.email.is_empty() {
+                    Err("The email is required".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .with_validator("email", |login| {
+                if login.email.contains('@') {
+                    Ok(())
+                } else {
+                    Err("The email is invalid".to_owned())
+                }
+            })
+            .with_validator("password", |login| {
+                if login.password.len() < 8 {
+                    Err("The password is too short".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+    }
+
+    #[test_case("", Err("The email is required".to_owned()) ; "empty email")]
+    #[test_case("hello", Err("The email is invalid".to_owned()) ; "invalid email")]
+    #[test_case("hello@example.com", Ok(()) ; "valid email")]
+    fn validate_reports_first_error(email: &str, expected: Result<(), String>) {
+        let mut state = login_form();
+        state.update("email", |login| login.email = email.to_owned());
+
+        assert_eq!(state.validate("email"), expected);
+    }
+
+    #[test_case("username" ; "field without validators")]
+    fn validate_without_validators_is_ok(field: &str) {
+        assert_eq!(login_form().validate(field), Ok(()));
+    }
+
+    #[test]
+    fn validation_is_hidden_until_reached() {
+        let mut state = login_form();
+        assert_eq!(state.validation("email"), None);
+        assert_eq!(state.validation("password"), None);
+
+        state.touch("email");
+        assert_eq!(
+            state.validation("email"),
+            Some(Err("The email is required".to_owned()))
+        );
+        assert_eq!(state.validation("password"), None);
+
+        state.update("password", |login| login.password = "secret".to_owned());
+        assert_eq!(
+            state.validation("password"),
+            Some(Err("The password is too short".to_owned()))
+        );
+    }
+
+    #[test]
+    fn submit_reports_all_fields() {
+        let mut state = login_form();
+        state.submit();
+
+        assert!(state.is_submitted());
+        assert!(state.validation("email").is_some());
+        assert!(state.validation("password").is_some());
+        assert_eq!(
+            state.errors(),
+            vec![
+                ("email", "The email is required".to_owned()),
+                ("password", "The password is too short".to_owned()),
+            ]
+        );
+    }
+
+    #[test_case("", "", false ; "empty")]
+    #[test_case("hello@example.com", "short", false ; "short password")]
+    #[test_case("hello@example.com", "long enough", true ; "valid")]
+    fn is_valid_checks_all_fields(email: &str, password: &str, expected: bool) {
+        let mut state = login_form();
+        state.update("email", |login| login.email = email.to_owned());
+        state.update("password", |login| login.password = password.to_owned());
+
+        assert_eq!(state.is_valid(), expected);
+    }
+
+    #[test]
+    fn dirty_compares_with_initial_values() {
+        let mut state = login_form();
+        state.update("email", |login| login.email = "hello".to_owned());
+        assert!(state.is_dirty());
+        assert!(state.is_field_dirty("email"));
+        assert!(!state.is_field_dirty("password"));
+
+        state.update("email", |login| login.email = "".to_owned());
+        assert!(!state.is_dirty());
+        assert!(state.is_field_dirty("email"));
+    }
+
+    #[test]
+    fn reset_restores_initial_state() {
+        let mut state = login_form();
+        state.update("email", |login| login.email = "hello".to_owned());
+        state.touch("password");
+        state.submit();
+        state.reset();
+
+        assert!(state.values() == &Login::default());
+        assert!(!state.is_dirty());
+        assert!(!state.is_field_dirty("email"));
+        assert!(!state.is_touched("password"));
+        assert!(!state.is_submitted());
+        assert_eq!(state.validation("email"), None);
+    }
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Profile {
+        age: u32,
+    }
+
+    fn parse_age(profile: &mut Profile, text: &str) -> Result<(), String> {
+        text.trim()
+            .parse()
+            .map(|age| profile.age = age)
+            .map_err(|_| "Age must be a number".to_owned())
+    }
+
+    #[test_case("42", 42, Ok(()) ; "number")]
+    #[test_case(" 42 ", 42, Ok(()) ; "padded number")]
+    #[test_case("", 7, Err("Age must be a number".to_owned()) ; "empty text")]
+    #[test_case("4x", 7, Err("Age must be a number".to_owned()) ; "invalid text")]
+    fn update_text_parses_values(text: &str, expected: u32, validation: Result<(), String>) {
+        let mut state = FormState::new(Profile { age: 7 });
+        state.update_text("age", text.to_owned(), parse_age);
+
+        assert_eq!(state.values().age, expected);
+        assert_eq!(state.text("age"), Some(text));
+        assert_eq!(state.validation("age"), Some(validation.clone()));
+        assert_eq!(state.is_valid(), validation.is_ok());
+    }
+
+    #[test]
+    fn update_text_reports_parse_errors() {
+        let mut state = FormState::new(Profile::default());
+        state.update_text("age", "4x".to_owned(), parse_age);
+
+        assert_eq!(
+            state.errors(),
+            vec![("age", "Age must be a number".to_owned())]
+        );
+
+        state.update_text("age", "42".to_owned(), parse_age);
+
+        assert!(state.errors().is_empty());
+        assert_eq!(state.values().age, 42);
+    }
+
+    #[test]
+    fn reset_clears_texts() {
+        let mut state = FormState::new(Profile::default());
+        state.update_text("age", "4x".to_owned(), parse_age);
+        state.reset();
+
+        assert_eq!(state.text("age"), None);
+        assert!(state.is_valid());
+    }
+}
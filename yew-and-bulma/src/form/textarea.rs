@@ -0,0 +1,395 @@
+use web_sys::HtmlTextAreaElement;
+use yew::{
+    function_component, html, html::TargetCast, AttrValue, Callback, Html, InputEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::general::{use_control_loading, use_validation_color},
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma textarea element][bd].
+///
+/// Defines the properties of the textarea element, based on the specification
+/// found in the [Bulma textarea element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::Textarea;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Textarea placeholder="e.g. Hello world" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TextareaProperties {
+    /// Sets the value of the [Bulma textarea element][bd].
+    ///
+    /// Sets the value of the [Bulma textarea element][bd] which will receive
+    /// these properties. Together with the `oninput` callback, it allows the
+    /// textarea to be controlled by the state of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea value="Hello world" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Sets the callback to be used when the [Bulma textarea element][bd] changes.
+    ///
+    /// Sets the callback to be used when the value of the
+    /// [Bulma textarea element][bd], which will receive these properties, is
+    /// changed by the user. The callback receives the new value of the
+    /// textarea.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let value = use_state(String::new);
+    ///     let oninput = {
+    ///         let value = value.clone();
+    ///         Callback::from(move |new_value: String| value.set(new_value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Textarea value={(*value).clone()} {oninput} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub oninput: Option<Callback<String>>,
+    /// Sets the placeholder of the [Bulma textarea element][bd].
+    ///
+    /// Sets the placeholder of the [Bulma textarea element][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea placeholder="e.g. Hello world" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Sets the name of the [Bulma textarea element][bd].
+    ///
+    /// Sets the [HTML name attribute][name] of the [Bulma textarea element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea name="message" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    /// [name]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea#attr-name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the number of rows of the [Bulma textarea element][bd].
+    ///
+    /// Sets the number of visible text lines of the
+    /// [Bulma textarea element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea rows={10} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#rows
+    #[prop_or_default]
+    pub rows: Option<u32>,
+    /// Whether or not the [Bulma textarea element][bd] has a fixed size.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, can not be resized by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea fixed_size=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#fixed-size
+    #[prop_or_default]
+    pub fixed_size: bool,
+    /// Sets the color of the [Bulma textarea element][bd].
+    ///
+    /// Sets the color of the [Bulma textarea element][bd] which will receive
+    /// these properties. When no color is set, the textarea follows the
+    /// validation result of the field containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::Textarea, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma textarea element][bd].
+    ///
+    /// Sets the size of the [Bulma textarea element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::Textarea, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [Bulma textarea element][bd] is read-only.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, is read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea value="Hello world" readonly=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Whether or not the [Bulma textarea element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::Textarea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Textarea disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Whether or not the [Bulma textarea element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, should display a loading spinner. Since Bulma
+    /// attaches the spinner to the control, the enclosing
+    /// [`crate::form::general::Control`] is marked as loading. Outside of a
+    /// control, the textarea is wrapped in a loading control of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{Control, Textarea};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control>
+    ///             <Textarea loading=true />
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub loading: bool,
+}
+
+/// Yew implementation of the [Bulma textarea element][bd].
+///
+/// Yew implementation of the textarea element, based on the specification
+/// found in the [Bulma textarea element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, Label, Textarea};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let message = use_state(String::new);
+///     let oninput = {
+///         let message = message.clone();
+///         Callback::from(move |value: String| message.set(value))
+///     };
+///
+///     html! {
+///         <Field>
+///             <Label>{"Message"}</Label>
+///             <Control>
+///                 <Textarea rows={4} value={(*message).clone()} {oninput} />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+#[function_component(Textarea)]
+pub fn textarea(props: &TextareaProperties) -> Html {
+    let in_control = use_control_loading(props.loading);
+    let oninput = {
+        let oninput = props.oninput.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(oninput) = &oninput {
+                oninput.emit(event.target_unchecked_into::<HtmlTextAreaElement>().value());
+            }
+        })
+    };
+    let size = props
+        .size
+        .map(|size| {
+            if Size::Normal == size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let validation_color = use_validation_color();
+    let class = ClassBuilder::default()
+        .with_custom_class("textarea")
+        .with_color(props.color.or(validation_color))
+        .with_custom_class(&size)
+        .with_custom_class(if props.fixed_size {
+            "has-fixed-size"
+        } else {
+            ""
+        })
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    let textarea = html! {
+        <textarea id={&props.id} {class} value={&props.value}
+            placeholder={&props.placeholder} name={&props.name}
+            rows={props.rows.map(|rows| rows.to_string())}
+            readonly={props.readonly} disabled={props.disabled} {oninput}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    };
+
+    if props.loading && !in_control {
+        html! {
+            <div class="control is-loading">
+                { textarea }
+            </div>
+        }
+    } else {
+        textarea
+    }
+}
@@ -61,7 +61,7 @@ pub mod color;
 /// * etc.
 ///
 /// > _More examples can be found in the [Bulma documentation][bd] and the
-/// reference pages, such as [Mozilla's][mdn]._
+/// > reference pages, such as [Mozilla's][mdn]._
 ///
 /// For the most part, they should be used with the
 /// [`crate::utils::class::ClassBuilder`] struct. They also require that the
@@ -209,7 +209,7 @@ pub mod spacing;
 /// * etc.
 ///
 /// > _More CSS properties such as those presented above can be found in the
-/// [Mozilla Developer Network Web Docs][mdn]_
+/// > [Mozilla Developer Network Web Docs][mdn]_
 ///
 /// For the most part, they should be use with the
 /// [`crate::utils::class::ClassBuilder`] struct.
@@ -10,7 +10,7 @@
 //! frontend frameworks, such as Angular or React.
 //!
 //! > _* It might not be possible to expose everything in the same manner as
-//! with JavaScript, but wherever it is, this crate will try and implement them._
+//! > with JavaScript, but wherever it is, this crate will try and implement them._
 //!
 //! ### Supported Targets (for Yew Client-Side Rendering only)
 //! - `wasm32-unknown-unknown`
@@ -52,6 +52,32 @@
 /// [bd]: https://bulma.io/documentation/columns/
 /// [yew]: https://yew.rs
 pub mod columns;
+/// Holds the [Bulma components][bd] implemented as [Yew components][yew].
+///
+/// Contains all of the [Bulma components][bd] implemented as
+/// [Yew components][yew].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="https://bulma.io">{"Bulma"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/
+/// [yew]: https://yew.rs
+pub mod components;
 /// Holds the [Bulma elements][bd] implemented as [Yew components][yew].
 ///
 /// Contains all of the [Bulma elements][bd] implemented as
@@ -74,6 +100,33 @@ pub mod columns;
 /// [bd]: https://bulma.io/documentation/elements/
 /// [yew]: https://yew.rs
 pub mod elements;
+/// Holds the [Bulma form elements][bd] implemented as [Yew components][yew].
+///
+/// Contains all of the [Bulma form elements][bd] implemented as
+/// [Yew components][yew].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{Control, Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/
+/// [yew]: https://yew.rs
+pub mod form;
 /// CSS helpers, as described in the [Bulma documentation][bd].
 ///
 /// Contains the [Bulma CSS helpers][bd] implementations for:
@@ -85,8 +138,8 @@ pub mod elements;
 /// * Flexbox (implemented in the [`crate::helpers::flexbox`] module)
 ///
 /// > _Since the helpers defined in the [Other documentation section][other]
-/// only contain individual classes, without much relation or customization to
-/// them, those are defined directly into [`crate::utils::constants`]._
+/// > only contain individual classes, without much relation or customization to
+/// > them, those are defined directly into [`crate::utils::constants`]._
 ///
 /// While it is possible to manually format each value, for example using the
 /// predefined strings in [`crate::utils::constants`], it is recommended to opt
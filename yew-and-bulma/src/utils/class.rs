@@ -269,7 +269,7 @@ impl ClassBuilder {
     /// checked it prior to the call.
     ///
     /// > _If you add the same class multiple times, it will only appear once
-    /// in the final list._
+    /// > in the final list._
     ///
     /// # Examples
     ///
@@ -505,7 +505,7 @@ impl ClassBuilder {
     /// current list of classes.
     ///
     /// > _If you add the same viewport size multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -620,7 +620,7 @@ impl ClassBuilder {
     /// the current list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -708,7 +708,7 @@ impl ClassBuilder {
     /// current list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -884,7 +884,7 @@ impl ClassBuilder {
     /// the current list of classes.
     ///
     /// > _If you add the same viewport display multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -1298,7 +1298,7 @@ impl ClassBuilder {
     /// list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -1370,7 +1370,7 @@ impl ClassBuilder {
     /// list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
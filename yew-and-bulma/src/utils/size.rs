@@ -30,7 +30,7 @@ use std::fmt::Display;
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Size {
     Small,
     Normal,